@@ -0,0 +1,88 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn dot(c: &mut Criterion) {
+    let mut g = c.benchmark_group("dot");
+    let (a, b) = (vecto::Vec2::new(1.5, -2.0), vecto::Vec2::new(0.25, 3.0));
+    g.bench_function("vecto", |bench| {
+        bench.iter(|| black_box(a).dot(&black_box(b)));
+    });
+    let (a, b) = (glam::Vec2::new(1.5, -2.0), glam::Vec2::new(0.25, 3.0));
+    g.bench_function("glam", |bench| {
+        bench.iter(|| black_box(a).dot(black_box(b)))
+    });
+    g.finish();
+}
+
+fn length(c: &mut Criterion) {
+    let mut g = c.benchmark_group("length");
+    let a = vecto::Vec2::new(1.5, -2.0);
+    g.bench_function("vecto", |bench| bench.iter(|| black_box(a).length()));
+    let a = glam::Vec2::new(1.5, -2.0);
+    g.bench_function("glam", |bench| bench.iter(|| black_box(a).length()));
+    g.finish();
+}
+
+fn rotate(c: &mut Criterion) {
+    let mut g = c.benchmark_group("rotate");
+    let a = vecto::Vec2::new(1.5, -2.0);
+    g.bench_function("vecto", |bench| {
+        bench.iter(|| black_box(a).rotated(black_box(0.7)));
+    });
+    let a = glam::Vec2::new(1.5, -2.0);
+    g.bench_function("glam", |bench| {
+        bench.iter(|| glam::Vec2::from_angle(black_box(0.7)).rotate(black_box(a)));
+    });
+    g.finish();
+}
+
+fn ops(c: &mut Criterion) {
+    let mut g = c.benchmark_group("mul_add");
+    let (a, b) = (vecto::Vec2::new(1.5, -2.0), vecto::Vec2::new(0.25, 3.0));
+    g.bench_function("vecto", |bench| {
+        bench.iter(|| black_box(a) * black_box(2.0) + black_box(b));
+    });
+    let (a, b) = (glam::Vec2::new(1.5, -2.0), glam::Vec2::new(0.25, 3.0));
+    g.bench_function("glam", |bench| {
+        bench.iter(|| black_box(a) * black_box(2.0) + black_box(b));
+    });
+    g.finish();
+}
+
+fn transform(c: &mut Criterion) {
+    let mut g = c.benchmark_group("transform_1024");
+    let t = vecto::Transform2D::new(
+        vecto::Vec2::new(0.8, 0.6),
+        vecto::Vec2::new(-0.6, 0.8),
+        vecto::Vec2::new(3.0, -1.0),
+    );
+    let mut points = vec![vecto::Vec2::new(1.5, -2.0); 1024];
+    g.bench_function("vecto (each)", |bench| {
+        bench.iter(|| {
+            let t = black_box(t);
+            for p in &mut points {
+                *p = t * *p;
+            }
+        });
+    });
+    g.bench_function("vecto (slice)", |bench| {
+        bench.iter(|| black_box(t).transform_slice(&mut points));
+    });
+    let t = glam::Affine2::from_cols(
+        glam::Vec2::new(0.8, 0.6),
+        glam::Vec2::new(-0.6, 0.8),
+        glam::Vec2::new(3.0, -1.0),
+    );
+    let mut points = vec![glam::Vec2::new(1.5, -2.0); 1024];
+    g.bench_function("glam", |bench| {
+        bench.iter(|| {
+            let t = black_box(t);
+            for p in &mut points {
+                *p = t.transform_point2(*p);
+            }
+        });
+    });
+    g.finish();
+}
+
+criterion_group!(benches, dot, length, rotate, ops, transform);
+criterion_main!(benches);
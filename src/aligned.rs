@@ -0,0 +1,51 @@
+use crate::Vec2;
+use core::ops::{Deref, DerefMut};
+
+/// A [`Vec2`] aligned to 8 bytes, so it loads and stores as a single 64 bit move.
+///
+/// Derefs to the inner [`Vec2`] for everything else.
+/// ```
+/// # use vecto::{AlignedVec2, Vec2};
+/// assert_eq!(std::mem::align_of::<AlignedVec2>(), 8);
+/// assert_eq!(std::mem::size_of::<AlignedVec2>(), std::mem::size_of::<Vec2>());
+/// let v = AlignedVec2::new(3.0, 4.0);
+/// assert_eq!(v.length(), 5.0);
+/// assert_eq!(*v + Vec2::splat(1.0), Vec2::new(4.0, 5.0));
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Default, Debug)]
+#[repr(C, align(8))]
+pub struct AlignedVec2(pub Vec2);
+
+impl AlignedVec2 {
+    /// Construct a new [`AlignedVec2`].
+    #[must_use]
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self(Vec2::new(x, y))
+    }
+}
+
+impl Deref for AlignedVec2 {
+    type Target = Vec2;
+
+    fn deref(&self) -> &Vec2 {
+        &self.0
+    }
+}
+
+impl DerefMut for AlignedVec2 {
+    fn deref_mut(&mut self) -> &mut Vec2 {
+        &mut self.0
+    }
+}
+
+impl From<Vec2> for AlignedVec2 {
+    fn from(value: Vec2) -> Self {
+        Self(value)
+    }
+}
+
+impl From<AlignedVec2> for Vec2 {
+    fn from(value: AlignedVec2) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,132 @@
+//! Helpers for angles in radians.
+use crate::Vec2;
+use std::f32::consts::{PI, TAU};
+
+/// Wraps `angle` into `-π..=π`, excluding `-π`.
+/// ```
+/// # use vecto::{Kinda, angle::wrap_angle};
+/// # use std::f32::consts::PI;
+/// assert!(wrap_angle(1.5 * PI).approx_eq(-0.5 * PI));
+/// assert_eq!(wrap_angle(-PI), PI);
+/// assert_eq!(wrap_angle(0.25), 0.25);
+/// ```
+#[must_use]
+pub fn wrap_angle(angle: f32) -> f32 {
+    let angle = normalize_angle_positive(angle);
+    if angle > PI {
+        angle - TAU
+    } else {
+        angle
+    }
+}
+
+/// Wraps `angle` into `0..τ`.
+/// ```
+/// # use vecto::{Kinda, angle::normalize_angle_positive};
+/// # use std::f32::consts::PI;
+/// assert!(normalize_angle_positive(-0.5 * PI).approx_eq(1.5 * PI));
+/// assert_eq!(normalize_angle_positive(2.0 * PI), 0.0);
+/// assert_eq!(normalize_angle_positive(-1e-9), 0.0);
+/// ```
+#[must_use]
+pub fn normalize_angle_positive(angle: f32) -> f32 {
+    let angle = angle.rem_euclid(TAU);
+    // tiny negative angles round up to τ
+    if angle >= TAU {
+        0.0
+    } else {
+        angle
+    }
+}
+
+/// Returns the shortest signed angle that turns `from` into `to`, in `-π..=π`.
+///
+/// Y is down, so positive differences are clockwise on screen.
+/// ```
+/// # use vecto::{Kinda, angle::angle_difference};
+/// # use std::f32::consts::PI;
+/// // across the ±π seam, the short way round
+/// assert!(angle_difference(0.9 * PI, -0.9 * PI).approx_eq(0.2 * PI));
+/// assert!(angle_difference(0.0, -0.5 * PI).approx_eq(-0.5 * PI));
+/// ```
+#[must_use]
+pub fn angle_difference(from: f32, to: f32) -> f32 {
+    wrap_angle(to - from)
+}
+
+/// Returns the unit vector pointing along `heading`, the same as [`Vec2::from_angle`].
+/// ```
+/// # use vecto::{Kinda, Vec2, angle::direction_from_heading};
+/// # use std::f32::consts::PI;
+/// assert!(direction_from_heading(0.5 * PI).approx_eq(Vec2::DOWN));
+/// ```
+#[must_use]
+pub fn direction_from_heading(heading: f32) -> Vec2 {
+    Vec2::from_angle(heading)
+}
+
+/// Returns the heading of `direction` in `-π..=π`, with 0 pointing right. The zero vector has heading 0.
+/// ```
+/// # use vecto::{Vec2, angle::heading_from_direction};
+/// # use std::f32::consts::PI;
+/// assert_eq!(heading_from_direction(Vec2::LEFT), PI);
+/// assert_eq!(heading_from_direction(Vec2::UP), -0.5 * PI);
+/// ```
+#[must_use]
+pub fn heading_from_direction(direction: Vec2) -> f32 {
+    direction.angle()
+}
+
+/// Turns `heading` at `angular_velocity` (radians per time unit) for `dt`, and returns the new heading wrapped into `-π..=π`.
+/// ```
+/// # use vecto::{Kinda, angle::integrate_heading};
+/// # use std::f32::consts::PI;
+/// assert!(integrate_heading(0.9 * PI, PI, 0.2).approx_eq(-0.9 * PI));
+/// ```
+#[must_use]
+pub fn integrate_heading(heading: f32, angular_velocity: f32, dt: f32) -> f32 {
+    wrap_angle(heading + angular_velocity * dt)
+}
+
+/// Changes `angular_velocity` towards `desired`, by at most `|max_acceleration * dt|`, and returns it.
+/// A negative limit is taken as its magnitude, so it never pushes away from `desired`.
+/// ```
+/// # use vecto::angle::accelerate_angular;
+/// assert_eq!(accelerate_angular(0.0, 3.0, 2.0, 0.5), 1.0);
+/// assert_eq!(accelerate_angular(0.0, 0.5, 2.0, 0.5), 0.5);
+/// assert_eq!(accelerate_angular(0.0, 3.0, 2.0, -0.5), 1.0);
+/// ```
+///
+/// # Panics
+///
+/// If `max_acceleration * dt` is NaN.
+#[must_use]
+pub fn accelerate_angular(
+    angular_velocity: f32,
+    desired: f32,
+    max_acceleration: f32,
+    dt: f32,
+) -> f32 {
+    let max_change = (max_acceleration * dt).abs();
+    angular_velocity + (desired - angular_velocity).clamp(-max_change, max_change)
+}
+
+/// Turns `from` towards `to` the short way round, by at most `|max_turn|`, and returns the new heading in `-π..=π`.
+/// For a turn rate limit, pass `turn_rate * dt`. The sign of `max_turn` is ignored.
+/// ```
+/// # use vecto::{Kinda, angle::turn_towards};
+/// # use std::f32::consts::PI;
+/// assert!(turn_towards(0.9 * PI, -0.9 * PI, 0.1 * PI).approx_eq(PI));
+/// assert!(turn_towards(0.0, 0.25, 1.0).approx_eq(0.25));
+/// assert!(turn_towards(0.0, 0.25, -0.1).approx_eq(0.1));
+/// ```
+///
+/// # Panics
+///
+/// If `max_turn` is NaN.
+#[must_use]
+pub fn turn_towards(from: f32, to: f32, max_turn: f32) -> f32 {
+    let difference = angle_difference(from, to);
+    let max_turn = max_turn.abs();
+    wrap_angle(from + difference.clamp(-max_turn, max_turn))
+}
@@ -0,0 +1,232 @@
+//! Statistics, orderings and cleanup over sets of points.
+//!
+//! The statistics return [`None`] for an empty slice.
+//! ```
+//! # use vecto::{Vec2, batch};
+//! let positions = [Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0), Vec2::new(4.0, 6.0)];
+//! assert_eq!(batch::mean(&positions), Some(Vec2::new(2.0, 2.0)));
+//! assert_eq!(batch::median(&positions), Some(Vec2::new(2.0, 0.0)));
+//! assert_eq!(batch::extents(&positions), Some((Vec2::ZERO, Vec2::new(4.0, 6.0))));
+//! assert_eq!(batch::mean(&[]), None);
+//! ```
+use crate::{robust::orient2d, Matrix2, Vec2};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+/// Returns the mean (centroid) of the points.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn mean(points: &[Vec2]) -> Option<Vec2> {
+    (!points.is_empty()).then(|| points.iter().sum::<Vec2>() / points.len() as f32)
+}
+
+/// Returns the per-component median of the points: the median X and the median Y, which need not come from the same point.
+///
+/// With an even number of points, the two middle values are averaged. Each component is sorted with [`f32::total_cmp`].
+/// ```
+/// # use vecto::{Vec2, batch};
+/// let points = [Vec2::new(1.0, 8.0), Vec2::new(4.0, 2.0), Vec2::new(2.0, 4.0), Vec2::new(100.0, 0.0)];
+/// assert_eq!(batch::median(&points), Some(Vec2::new(3.0, 3.0)));
+/// ```
+#[must_use]
+pub fn median(points: &[Vec2]) -> Option<Vec2> {
+    if points.is_empty() {
+        return None;
+    }
+    let median = |mut values: Vec<f32>| {
+        values.sort_unstable_by(f32::total_cmp);
+        let mid = values.len() / 2;
+        if values.len().is_multiple_of(2) {
+            values[mid - 1].midpoint(values[mid])
+        } else {
+            values[mid]
+        }
+    };
+    Some(Vec2::new(
+        median(points.iter().map(|p| p.x).collect()),
+        median(points.iter().map(|p| p.y).collect()),
+    ))
+}
+
+/// Returns the per-component (population) variance of the points, the mean squared distance from the [`mean`] along each axis.
+/// ```
+/// # use vecto::{Vec2, batch};
+/// let points = [Vec2::new(-1.0, 5.0), Vec2::new(1.0, 5.0)];
+/// assert_eq!(batch::variance(&points), Some(Vec2::new(1.0, 0.0)));
+/// ```
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn variance(points: &[Vec2]) -> Option<Vec2> {
+    let mean = mean(points)?;
+    Some(
+        points
+            .iter()
+            .map(|&p| (p - mean) * (p - mean))
+            .sum::<Vec2>()
+            / points.len() as f32,
+    )
+}
+
+/// Returns the per-component (population) standard deviation of the points, the square root of the [`variance`].
+/// ```
+/// # use vecto::{Vec2, batch};
+/// let points = [Vec2::new(-2.0, 0.0), Vec2::new(2.0, 0.0)];
+/// assert_eq!(batch::std_dev(&points), Some(Vec2::new(2.0, 0.0)));
+/// ```
+#[must_use]
+pub fn std_dev(points: &[Vec2]) -> Option<Vec2> {
+    variance(points).map(Vec2::sqrt)
+}
+
+/// Returns the (population) covariance matrix of the points.
+///
+/// Its diagonal is the [`variance`]; its [eigenvectors](Matrix2::eigenvectors) are the directions of greatest and least spread.
+/// ```
+/// # use vecto::{Matrix2, Vec2, batch};
+/// let diagonal = [Vec2::new(-1.0, -1.0), Vec2::new(1.0, 1.0)];
+/// assert_eq!(batch::covariance(&diagonal), Some(Matrix2::new(Vec2::splat(1.0), Vec2::splat(1.0))));
+/// ```
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn covariance(points: &[Vec2]) -> Option<Matrix2<f32>> {
+    let mean = mean(points)?;
+    let sum = points
+        .iter()
+        .map(|&p| (p - mean).outer(p - mean))
+        .fold(Matrix2::default(), |a, b| a + b);
+    Some(sum * (1.0 / points.len() as f32))
+}
+
+/// Returns the per-component minimum and maximum of the points, the corners of their bounding box.
+///
+/// See also [`Rect2::from_points`](crate::Rect2::from_points).
+#[must_use]
+pub fn extents(points: &[Vec2]) -> Option<(Vec2, Vec2)> {
+    let (&first, rest) = points.split_first()?;
+    Some(rest.iter().fold((first, first), |(min, max), p| {
+        (
+            Vec2::new(min.x.min(p.x), min.y.min(p.y)),
+            Vec2::new(max.x.max(p.x), max.y.max(p.y)),
+        )
+    }))
+}
+
+/// Orders `a` and `b` by their angle around `pivot`, as [`Vec2::angle`] of their offsets from it but in `0..τ`:
+/// starting from the +X axis and turning clockwise on screen (as Y is down).
+///
+/// Uses exact cross products rather than trigonometry, so the order is consistent. Points at the same angle
+/// (collinear with the pivot, on the same side) are ordered nearest first, and points equal to the pivot come before all others.
+/// ```
+/// # use vecto::{Vec2, batch::compare_angles_around};
+/// # use std::cmp::Ordering;
+/// let p = Vec2::ZERO;
+/// assert_eq!(compare_angles_around(p, Vec2::RIGHT, Vec2::DOWN), Ordering::Less);
+/// assert_eq!(compare_angles_around(p, Vec2::UP, Vec2::LEFT), Ordering::Greater);
+/// assert_eq!(compare_angles_around(p, Vec2::RIGHT, Vec2::RIGHT * 2.0), Ordering::Less);
+/// // a negative zero is still on the +X axis
+/// let (a, b, c) = (Vec2::new(1.0, -0.0), Vec2::new(-2.0, 0.0), Vec2::new(0.0, -1.0));
+/// assert_eq!(compare_angles_around(p, a, b), Ordering::Less);
+/// assert_eq!(compare_angles_around(p, b, c), Ordering::Less);
+/// assert_eq!(compare_angles_around(p, a, c), Ordering::Less);
+/// ```
+#[must_use]
+pub fn compare_angles_around(pivot: Vec2, a: Vec2, b: Vec2) -> Ordering {
+    // the angles in 0..π come first; `+ 0.0` turns -0.0 into 0.0, which `total_cmp` would put below it
+    let half = |p: Vec2| match (
+        (p.y + 0.0).total_cmp(&(pivot.y + 0.0)),
+        (p.x + 0.0).total_cmp(&(pivot.x + 0.0)),
+    ) {
+        (Ordering::Equal, Ordering::Equal) => 0,
+        (Ordering::Greater, _) | (Ordering::Equal, Ordering::Greater) => 1,
+        _ => 2,
+    };
+    half(a)
+        .cmp(&half(b))
+        .then_with(|| {
+            0.0f64
+                .partial_cmp(&orient2d(pivot, a, b))
+                .unwrap_or(Ordering::Equal)
+        })
+        .then_with(|| {
+            pivot
+                .distance_squared_to(&a)
+                .total_cmp(&pivot.distance_squared_to(&b))
+        })
+}
+
+/// Sorts the points by their angle around `pivot`; see [`compare_angles_around`] for the order.
+/// ```
+/// # use vecto::{Vec2, batch::sort_by_angle_around};
+/// let mut points = [Vec2::UP, Vec2::LEFT * 2.0, Vec2::DOWN, Vec2::LEFT, Vec2::RIGHT];
+/// sort_by_angle_around(Vec2::ZERO, &mut points);
+/// assert_eq!(points, [Vec2::RIGHT, Vec2::DOWN, Vec2::LEFT, Vec2::LEFT * 2.0, Vec2::UP]);
+/// ```
+pub fn sort_by_angle_around(pivot: Vec2, points: &mut [Vec2]) {
+    points.sort_by(|&a, &b| compare_angles_around(pivot, a, b));
+}
+
+/// Sorts the points by X, then by Y, each compared with [`f32::total_cmp`].
+/// ```
+/// # use vecto::{Vec2, batch::sort_lexicographic};
+/// let mut points = [Vec2::new(1.0, 0.0), Vec2::new(0.0, 5.0), Vec2::new(0.0, -1.0)];
+/// sort_lexicographic(&mut points);
+/// assert_eq!(points, [Vec2::new(0.0, -1.0), Vec2::new(0.0, 5.0), Vec2::new(1.0, 0.0)]);
+/// ```
+pub fn sort_lexicographic(points: &mut [Vec2]) {
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+}
+
+/// Removes exact duplicates, keeping the first of each in order. `0.0` and `-0.0` count as equal.
+/// ```
+/// # use vecto::{Vec2, batch::dedup};
+/// let mut points = vec![Vec2::ZERO, Vec2::RIGHT, Vec2::new(-0.0, 0.0), Vec2::RIGHT];
+/// dedup(&mut points);
+/// assert_eq!(points, [Vec2::ZERO, Vec2::RIGHT]);
+/// ```
+pub fn dedup(points: &mut Vec<Vec2>) {
+    let mut seen = HashSet::with_capacity(points.len());
+    points.retain(|p| seen.insert(p.map(|c| (c + 0.0).to_bits())));
+}
+
+/// Merges points within `epsilon` of each other, as when welding the vertices of a mesh.
+///
+/// Returns the merged points, and for each input point the index of the merged point it became.
+/// Each merged point is the first input point of its group; later points join it if they are within `epsilon` of it.
+/// ```
+/// # use vecto::{Vec2, batch::weld};
+/// let imported = [Vec2::ZERO, Vec2::new(1.0, 0.0), Vec2::new(0.0001, 0.0), Vec2::new(1.0, 0.00005)];
+/// let (points, remap) = weld(&imported, 0.001);
+/// assert_eq!(points, [Vec2::ZERO, Vec2::new(1.0, 0.0)]);
+/// assert_eq!(remap, [0, 1, 0, 1]);
+/// ```
+///
+/// # Panics
+///
+/// If `epsilon` isn't positive.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn weld(points: &[Vec2], epsilon: f32) -> (Vec<Vec2>, Vec<usize>) {
+    assert!(epsilon > 0.0, "epsilon must be positive");
+    // bucket the merged points by an epsilon sized grid, so only neighbouring cells need checking
+    let cell = |p: Vec2| (p / epsilon).floor().map(|c| c as i64);
+    let mut grid: HashMap<_, Vec<usize>> = HashMap::new();
+    let mut merged: Vec<Vec2> = vec![];
+    let remap = points
+        .iter()
+        .map(|&p| {
+            let home = cell(p);
+            let existing = (-1..=1)
+                .flat_map(|dy| (-1..=1).map(move |dx| home + (dx, dy)))
+                .filter_map(|c| grid.get(&c))
+                .flatten()
+                .copied()
+                .find(|&i: &usize| merged[i].distance_squared_to(&p) <= epsilon * epsilon);
+            existing.unwrap_or_else(|| {
+                merged.push(p);
+                grid.entry(home).or_default().push(merged.len() - 1);
+                merged.len() - 1
+            })
+        })
+        .collect();
+    (merged, remap)
+}
@@ -0,0 +1,288 @@
+//! Parametric curves, arcs and arc-length parameterization.
+use crate::{Rect2, Vec2};
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+/// Evaluates a cubic Bezier curve at `t` (0..=1).
+/// ```
+/// # use vecto::{Vec2, curve::cubic_bezier};
+/// let (a, b, c, d) = (Vec2::ZERO, Vec2::new(0.0, 1.0), Vec2::new(1.0, 1.0), Vec2::new(1.0, 0.0));
+/// assert_eq!(cubic_bezier(a, b, c, d, 0.0), a);
+/// assert_eq!(cubic_bezier(a, b, c, d, 1.0), d);
+/// assert_eq!(cubic_bezier(a, b, c, d, 0.5), Vec2::new(0.5, 0.75));
+/// ```
+#[must_use]
+pub fn cubic_bezier(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, t: f32) -> Vec2 {
+    let u = 1.0 - t;
+    p0 * (u * u * u) + p1 * (3.0 * u * u * t) + p2 * (3.0 * u * t * t) + p3 * (t * t * t)
+}
+
+/// Evaluates the (uniform) Catmull-Rom segment between `p1` and `p2` at `t` (0..=1).
+/// ```
+/// # use vecto::{Vec2, curve::catmull_rom};
+/// let p = [Vec2::ZERO, Vec2::RIGHT, Vec2::new(2.0, 0.0), Vec2::new(3.0, 0.0)];
+/// assert_eq!(catmull_rom(p[0], p[1], p[2], p[3], 0.0), p[1]);
+/// assert_eq!(catmull_rom(p[0], p[1], p[2], p[3], 0.5), Vec2::new(1.5, 0.0));
+/// ```
+#[must_use]
+pub fn catmull_rom(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, t: f32) -> Vec2 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    (p1 * 2.0
+        + (p2 - p0) * t
+        + (p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * t2
+        + (p1 * 3.0 - p0 - p2 * 3.0 + p3) * t3)
+        * 0.5
+}
+
+/// Arc-length lookup table for a curve parameterized over `t` in 0..=1.
+///
+/// Maps distances along the curve back to `t`, so the curve can be traversed at constant speed.
+/// Lookups are a binary search, O(log n) in the number of samples.
+/// ```
+/// # use vecto::{Vec2, Kinda, curve::{ArcLength, cubic_bezier}};
+/// let (a, b, c, d) = (Vec2::ZERO, Vec2::new(0.0, 1.0), Vec2::new(1.0, 1.0), Vec2::new(1.0, 0.0));
+/// let curve = ArcLength::new(|t| cubic_bezier(a, b, c, d, t), 256);
+/// assert!(curve.point_at_distance(0.0).approx_eq(a));
+/// assert!(curve.point_at_distance(curve.length()).approx_eq(d));
+/// // symmetric curve, so the midpoint by distance is the midpoint by t
+/// assert!(curve.t_at_distance(curve.length() / 2.0).kinda_eq(0.5, 0.001));
+/// ```
+#[derive(Clone)]
+pub struct ArcLength<F> {
+    curve: F,
+    /// Cumulative length at `t = i / (lengths.len() - 1)`.
+    lengths: Vec<f32>,
+}
+
+impl<F: Fn(f32) -> Vec2> ArcLength<F> {
+    /// Builds the table by sampling `curve` at `samples + 1` evenly spaced `t`s.
+    ///
+    /// More samples give a more accurate table.
+    ///
+    /// # Panics
+    ///
+    /// If `samples` is zero.
+    #[must_use]
+    pub fn new(curve: F, samples: usize) -> Self {
+        assert!(samples > 0, "need at least one sample");
+        let mut lengths = Vec::with_capacity(samples + 1);
+        let mut last = curve(0.0);
+        let mut total = 0.0;
+        lengths.push(0.0);
+        for i in 1..=samples {
+            #[allow(clippy::cast_precision_loss)]
+            let p = curve(i as f32 / samples as f32);
+            total += last.distance_to(&p);
+            lengths.push(total);
+            last = p;
+        }
+        Self { curve, lengths }
+    }
+
+    /// Returns the (approximate) total length of the curve.
+    #[must_use]
+    pub fn length(&self) -> f32 {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// Returns the `t` at which the curve has covered `distance`. `distance` is clamped to the curve.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn t_at_distance(&self, distance: f32) -> f32 {
+        let distance = distance.clamp(0.0, self.length());
+        let i = self.lengths.partition_point(|&l| l < distance);
+        if i == 0 {
+            return 0.0;
+        }
+        let (before, after) = (self.lengths[i - 1], self.lengths[i]);
+        let segment = after - before;
+        let frac = if segment > 0.0 {
+            (distance - before) / segment
+        } else {
+            0.0
+        };
+        ((i - 1) as f32 + frac) / (self.lengths.len() - 1) as f32
+    }
+
+    /// Returns the point at `distance` along the curve.
+    #[must_use]
+    pub fn point_at_distance(&self, distance: f32) -> Vec2 {
+        (self.curve)(self.t_at_distance(distance))
+    }
+
+    /// Returns an iterator over points spaced `step` apart along the curve, starting at the beginning and ending at (or before) the end.
+    /// ```
+    /// # use vecto::{Vec2, curve::ArcLength};
+    /// let line = ArcLength::new(|t| Vec2::new(t * 10.0, 0.0), 4);
+    /// assert_eq!(line.points_by_distance(2.5).count(), 5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `step` is not positive.
+    pub fn points_by_distance(&self, step: f32) -> impl Iterator<Item = Vec2> + '_ {
+        assert!(step > 0.0, "step must be positive");
+        let len = self.length();
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let n = (len / step).floor() as usize;
+        #[allow(clippy::cast_precision_loss)]
+        (0..=n).map(move |i| self.point_at_distance(i as f32 * step))
+    }
+}
+
+/// The direction an [`Arc`] travels in, from its start angle to its end angle.
+///
+/// Y is down, so increasing angles turn clockwise on screen.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Winding {
+    /// Increasing angle.
+    #[default]
+    Clockwise,
+    /// Decreasing angle.
+    CounterClockwise,
+}
+
+/// A circular arc.
+/// ```
+/// # use vecto::{Vec2, Kinda, curve::{Arc, Winding}};
+/// # use std::f32::consts::PI;
+/// let arc = Arc::new(Vec2::ZERO, 2.0, 0.0, PI, Winding::Clockwise);
+/// assert!(arc.sample(0.5).approx_eq(Vec2::new(0.0, 2.0)));
+/// assert!(arc.length().approx_eq(2.0 * PI));
+/// // the same angles, the other way around
+/// let arc = Arc { winding: Winding::CounterClockwise, ..arc };
+/// assert!(arc.sample(0.5).approx_eq(Vec2::new(0.0, -2.0)));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Arc {
+    /// The center of the circle.
+    pub center: Vec2,
+    /// The radius of the circle.
+    pub radius: f32,
+    /// The angle the arc starts at, in radians.
+    pub start_angle: f32,
+    /// The angle the arc ends at, in radians.
+    pub end_angle: f32,
+    /// Which way the arc goes from `start_angle` to `end_angle`.
+    pub winding: Winding,
+}
+
+impl Arc {
+    /// Construct a new [`Arc`].
+    #[must_use]
+    pub const fn new(
+        center: Vec2,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        winding: Winding,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+            winding,
+        }
+    }
+
+    /// Returns the signed angle covered by the arc, in radians. Positive for [`Winding::Clockwise`].
+    ///
+    /// Angles a nonzero multiple of a full turn apart give a full circle, not an empty arc.
+    /// ```
+    /// # use vecto::{Vec2, curve::{Arc, Winding}};
+    /// # use std::f32::consts::TAU;
+    /// let ring = Arc::new(Vec2::ZERO, 1.0, 0.0, TAU, Winding::CounterClockwise);
+    /// assert_eq!(ring.sweep(), -TAU);
+    /// assert_eq!(ring.length(), TAU);
+    /// assert_eq!(Arc { end_angle: 0.0, ..ring }.sweep(), 0.0);
+    /// ```
+    #[must_use]
+    pub fn sweep(&self) -> f32 {
+        let sweep = match self.winding {
+            Winding::Clockwise => self.end_angle - self.start_angle,
+            Winding::CounterClockwise => self.start_angle - self.end_angle,
+        };
+        let wrapped = match sweep.rem_euclid(TAU) {
+            0.0 if sweep != 0.0 => TAU,
+            x => x,
+        };
+        match self.winding {
+            Winding::Clockwise => wrapped,
+            Winding::CounterClockwise => -wrapped,
+        }
+    }
+
+    /// Returns the point at `t` (0..=1) along the arc.
+    #[must_use]
+    pub fn sample(&self, t: f32) -> Vec2 {
+        self.center + Vec2::from_angle(self.start_angle + self.sweep() * t) * self.radius
+    }
+
+    /// Returns the length of the arc.
+    #[must_use]
+    pub fn length(&self) -> f32 {
+        self.sweep().abs() * self.radius
+    }
+
+    /// Returns the smallest [`Rect2`] containing the arc.
+    /// ```
+    /// # use vecto::{Vec2, Rect2, Kinda, curve::{Arc, Winding}};
+    /// # use std::f32::consts::PI;
+    /// let r = Arc::new(Vec2::ZERO, 1.0, -PI / 4.0, PI / 4.0, Winding::Clockwise).bounding_box();
+    /// assert!(r.position.approx_eq(Vec2::new(0.5f32.sqrt(), -0.5f32.sqrt())));
+    /// assert!(r.end().approx_eq(Vec2::new(1.0, 0.5f32.sqrt())));
+    /// ```
+    #[must_use]
+    pub fn bounding_box(&self) -> Rect2<f32> {
+        let sweep = self.sweep();
+        let within = |angle: f32| match self.winding {
+            Winding::Clockwise => (angle - self.start_angle).rem_euclid(TAU) <= sweep,
+            Winding::CounterClockwise => (self.start_angle - angle).rem_euclid(TAU) <= -sweep,
+        };
+        [0.0, FRAC_PI_2, PI, PI + FRAC_PI_2]
+            .into_iter()
+            .filter(|&a| within(a))
+            .map(|a| self.center + Vec2::from_angle(a) * self.radius)
+            .fold(
+                Rect2::new(self.sample(0.0), Vec2::ZERO).expand(self.sample(1.0)),
+                Rect2::expand,
+            )
+    }
+
+    /// Approximates the arc with a polyline whose points deviate at most `tolerance` from the true arc.
+    /// Includes both endpoints.
+    ///
+    /// `tolerance` is raised to at least `radius * 1e-4`, so a zero or negative tolerance still gives a finite polyline.
+    /// ```
+    /// # use vecto::{Vec2, Kinda, curve::{Arc, Winding}};
+    /// # use std::f32::consts::PI;
+    /// let arc = Arc::new(Vec2::ZERO, 10.0, 0.0, PI, Winding::Clockwise);
+    /// let points = arc.flatten(0.1);
+    /// assert!(points[0].approx_eq(arc.sample(0.0)));
+    /// assert!(points.last().unwrap().approx_eq(arc.sample(1.0)));
+    /// for w in points.windows(2) {
+    ///     // the midpoint of every chord is within tolerance of the circle
+    ///     assert!(10.0 - ((w[0] + w[1]) / 2.0).length() <= 0.1);
+    /// }
+    /// assert!(arc.flatten(0.0).len() < 1000);
+    /// ```
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    pub fn flatten(&self, tolerance: f32) -> Vec<Vec2> {
+        let sweep = self.sweep().abs();
+        let tolerance = tolerance.max(self.radius * 1e-4);
+        // the largest angle step whose chord stays within tolerance
+        let step = if tolerance < self.radius {
+            2.0 * (1.0 - tolerance / self.radius).acos()
+        } else {
+            PI
+        };
+        let n = ((sweep / step).ceil() as usize).max(1);
+        (0..=n).map(|i| self.sample(i as f32 / n as f32)).collect()
+    }
+}
@@ -0,0 +1,160 @@
+//! [Easing functions](https://easings.net), for motion that speeds up or slows down instead of moving at a constant rate.
+use crate::Vec2;
+use std::f32::consts::TAU;
+
+/// An easing curve, mapping progress in `0..=1` to eased progress.
+///
+/// The `In` curves start slow, the `Out` curves end slow, and the `InOut` curves do both.
+/// The `Out` curve is the `In` curve rotated by half a turn, and the `InOut` curve joins the two halves.
+/// ```
+/// # use vecto::ease::Easing;
+/// assert_eq!(Easing::QuadIn.ease(0.5), 0.25);
+/// assert_eq!(Easing::QuadOut.ease(0.5), 0.75);
+/// assert_eq!(Easing::CubicInOut.ease(0.25), 0.0625);
+/// assert!(Easing::BackIn.ease(0.2) < 0.0); // pulls back first
+/// assert!(Easing::ElasticOut.ease(0.2) > 1.0); // overshoots
+/// assert_eq!(Easing::BounceOut.ease(1.0), 1.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Easing {
+    /// Constant speed.
+    #[default]
+    Linear,
+    /// Quadratic, `t²`.
+    QuadIn,
+    /// Quadratic, ending slow.
+    QuadOut,
+    /// Quadratic, starting and ending slow.
+    QuadInOut,
+    /// Cubic, `t³`.
+    CubicIn,
+    /// Cubic, ending slow.
+    CubicOut,
+    /// Cubic, starting and ending slow.
+    CubicInOut,
+    /// Exponential, `2^(10t - 10)`.
+    ExpoIn,
+    /// Exponential, ending slow.
+    ExpoOut,
+    /// Exponential, starting and ending slow.
+    ExpoInOut,
+    /// Backs up a little before moving forwards.
+    BackIn,
+    /// Overshoots a little before settling.
+    BackOut,
+    /// Backs up at the start and overshoots at the end.
+    BackInOut,
+    /// Winds up with growing oscillation.
+    ElasticIn,
+    /// Settles with decaying oscillation, like a spring.
+    ElasticOut,
+    /// Oscillates at both ends.
+    ElasticInOut,
+    /// Bounces off the start, the reverse of [`Easing::BounceOut`].
+    BounceIn,
+    /// Falls and bounces to rest, like a dropped ball.
+    BounceOut,
+    /// Bounces at both ends.
+    BounceInOut,
+}
+
+macro_rules! curves {
+    ($($curve:ident => $in:ident, $out:ident, $in_out:ident;)+) => {
+        impl Easing {
+            /// Returns the eased progress for `t`, clamped to `0..=1`.
+            ///
+            /// The result is exactly 0 at the start and 1 at the end, but may leave `0..=1` in between.
+            #[must_use]
+            pub fn ease(self, t: f32) -> f32 {
+                if t.is_nan() || t <= 0.0 {
+                    return 0.0;
+                }
+                if t >= 1.0 {
+                    return 1.0;
+                }
+                match self {
+                    Self::Linear => t,
+                    $(
+                        Self::$in => $curve(t),
+                        Self::$out => 1.0 - $curve(1.0 - t),
+                        Self::$in_out => {
+                            if t < 0.5 {
+                                $curve(2.0 * t) / 2.0
+                            } else {
+                                1.0 - $curve(2.0 - 2.0 * t) / 2.0
+                            }
+                        }
+                    )+
+                }
+            }
+        }
+    };
+}
+curves! {
+    quad => QuadIn, QuadOut, QuadInOut;
+    cubic => CubicIn, CubicOut, CubicInOut;
+    expo => ExpoIn, ExpoOut, ExpoInOut;
+    back => BackIn, BackOut, BackInOut;
+    elastic => ElasticIn, ElasticOut, ElasticInOut;
+    bounce => BounceIn, BounceOut, BounceInOut;
+}
+
+// the `In` curves, each 0 at 0 and 1 at 1
+
+fn quad(t: f32) -> f32 {
+    t * t
+}
+
+fn cubic(t: f32) -> f32 {
+    t * t * t
+}
+
+fn expo(t: f32) -> f32 {
+    (10.0 * t - 10.0).exp2()
+}
+
+fn back(t: f32) -> f32 {
+    const OVERSHOOT: f32 = 1.701_58;
+    t * t * (t + OVERSHOOT * (t - 1.0))
+}
+
+fn elastic(t: f32) -> f32 {
+    -(10.0 * t - 10.0).exp2() * ((10.0 * t - 10.75) * (TAU / 3.0)).sin()
+}
+
+fn bounce(t: f32) -> f32 {
+    // the falling ball, reversed
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    let t = 1.0 - t;
+    let out = if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984_375
+    };
+    1.0 - out
+}
+
+impl Vec2 {
+    /// Interpolates from `self` to `to` along the `easing` curve, where `t` of 0 gives `self` and 1 gives `to`.
+    ///
+    /// Same as [`Vec2::lerp`] with `easing.ease(t)`, so `t` is clamped to `0..=1`.
+    /// ```
+    /// # use vecto::{Vec2, ease::Easing};
+    /// let to = Vec2::new(4.0, 8.0);
+    /// assert_eq!(Vec2::ZERO.lerp_eased(to, 0.5, Easing::QuadIn), Vec2::new(1.0, 2.0));
+    /// assert_eq!(Vec2::ZERO.lerp_eased(to, 2.0, Easing::QuadIn), to);
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn lerp_eased(self, to: Self, t: f32, easing: Easing) -> Self {
+        self.lerp(to, easing.ease(t))
+    }
+}
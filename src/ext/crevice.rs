@@ -0,0 +1,31 @@
+use crate::Vector2;
+use crevice::{std140, std430};
+
+macro_rules! layout {
+    ($($t:ty => $vec:ident),+) => {$(
+        impl std140::AsStd140 for Vector2<$t> {
+            type Output = std140::$vec;
+
+            fn as_std140(&self) -> Self::Output {
+                std140::$vec { x: self.x, y: self.y }
+            }
+
+            fn from_std140(val: Self::Output) -> Self {
+                Self::new(val.x, val.y)
+            }
+        }
+
+        impl std430::AsStd430 for Vector2<$t> {
+            type Output = std430::$vec;
+
+            fn as_std430(&self) -> Self::Output {
+                std430::$vec { x: self.x, y: self.y }
+            }
+
+            fn from_std430(val: Self::Output) -> Self {
+                Self::new(val.x, val.y)
+            }
+        }
+    )+};
+}
+layout!(f32 => Vec2, f64 => DVec2, i32 => IVec2, u32 => UVec2);
@@ -0,0 +1,8 @@
+use crate::Vector2;
+use defmt::{Format, Formatter};
+
+impl<T: Format> Format for Vector2<T> {
+    fn format(&self, f: Formatter) {
+        defmt::write!(f, "({}, {})", self.x, self.y);
+    }
+}
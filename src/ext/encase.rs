@@ -0,0 +1,4 @@
+use crate::Vector2;
+
+// lays the vector out as a WGSL `vec2<T>`, for `f32`, `i32` and `u32` components
+encase::impl_vector!(2, Vector2<T>; using AsRef AsMut From);
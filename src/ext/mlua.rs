@@ -0,0 +1,87 @@
+use crate::Vec2;
+use mlua::{
+    Error, FromLua, Lua, MetaMethod, Result, UserData, UserDataFields, UserDataMethods, Value,
+};
+
+/// Exposes `x` and `y` fields, arithmetic (with vectors or numbers), `==`, `tostring`,
+/// and the methods `length`, `normalized`, `rotated`, `lerp`, `dot` and `distance_to`.
+///
+/// `new` is a static function, reachable through [`Lua::create_proxy`].
+/// ```
+/// # use vecto::Vec2;
+/// let lua = mlua::Lua::new();
+/// lua.globals().set("Vec2", lua.create_proxy::<Vec2>()?)?;
+/// let v: Vec2 = lua.load("(Vec2.new(1, 2) + Vec2.new(2, 2)) * 2").eval()?;
+/// assert_eq!(v, Vec2::new(6.0, 8.0));
+/// let l: f32 = lua.load("local v = Vec2.new(3, 0); v.y = 4; return v:length()").eval()?;
+/// assert_eq!(l, 5.0);
+/// # Ok::<(), mlua::Error>(())
+/// ```
+impl UserData for Vec2 {
+    fn add_fields<'lua, F: UserDataFields<'lua, Self>>(fields: &mut F) {
+        fields.add_field_method_get("x", |_, this| Ok(this.x));
+        fields.add_field_method_get("y", |_, this| Ok(this.y));
+        fields.add_field_method_set("x", |_, this, x| {
+            this.x = x;
+            Ok(())
+        });
+        fields.add_field_method_set("y", |_, this, y| {
+            this.y = y;
+            Ok(())
+        });
+    }
+
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_function("new", |_, (x, y): (f32, f32)| Ok(Vec2::new(x, y)));
+        methods.add_method("length", |_, this, ()| Ok(this.length()));
+        methods.add_method("normalized", |_, this, ()| Ok(this.normalized()));
+        methods.add_method("rotated", |_, this, angle: f32| Ok(this.rotated(angle)));
+        methods.add_method("lerp", |_, this, (to, t): (Vec2, f32)| Ok(this.lerp(to, t)));
+        methods.add_method("dot", |_, this, with: Vec2| Ok(this.dot(&with)));
+        methods.add_method("distance_to", |_, this, to: Vec2| Ok(this.distance_to(&to)));
+
+        // either operand may be a number, which is splatted
+        methods.add_meta_function(MetaMethod::Add, |_, (a, b): (Operand, Operand)| {
+            Ok(a.0 + b.0)
+        });
+        methods.add_meta_function(MetaMethod::Sub, |_, (a, b): (Operand, Operand)| {
+            Ok(a.0 - b.0)
+        });
+        methods.add_meta_function(MetaMethod::Mul, |_, (a, b): (Operand, Operand)| {
+            Ok(a.0 * b.0)
+        });
+        methods.add_meta_function(MetaMethod::Div, |_, (a, b): (Operand, Operand)| {
+            Ok(a.0 / b.0)
+        });
+        methods.add_meta_method(MetaMethod::Unm, |_, this, ()| Ok(-*this));
+        methods.add_meta_method(MetaMethod::Eq, |_, this, other: Vec2| Ok(*this == other));
+        methods.add_meta_method(MetaMethod::ToString, |_, this, ()| Ok(format!("{this:?}")));
+    }
+}
+
+impl<'lua> FromLua<'lua> for Vec2 {
+    fn from_lua(value: Value<'lua>, _: &'lua Lua) -> Result<Self> {
+        match value {
+            Value::UserData(ud) => Ok(*ud.borrow::<Self>()?),
+            _ => Err(Error::FromLuaConversionError {
+                from: value.type_name(),
+                to: "Vec2",
+                message: None,
+            }),
+        }
+    }
+}
+
+/// A [`Vec2`], or a number to splat into one.
+struct Operand(Vec2);
+
+impl<'lua> FromLua<'lua> for Operand {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn from_lua(value: Value<'lua>, lua: &'lua Lua) -> Result<Self> {
+        match value {
+            Value::Integer(n) => Ok(Self(Vec2::splat(n as f32))),
+            Value::Number(n) => Ok(Self(Vec2::splat(n as f32))),
+            value => Vec2::from_lua(value, lua).map(Self),
+        }
+    }
+}
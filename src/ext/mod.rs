@@ -0,0 +1,22 @@
+//! Implementations of other crates' traits, each behind the feature of the same name (except for umath, which is always used).
+#[cfg(feature = "crevice")]
+mod crevice;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "encase")]
+mod encase;
+#[cfg(feature = "mlua")]
+mod mlua;
+#[cfg(feature = "ordered-float")]
+mod ordered_float;
+#[cfg(feature = "pyo3")]
+mod pyo3;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "speedy")]
+mod speedy;
+#[cfg(feature = "sqlx-postgres")]
+mod sqlx;
+mod umath;
+#[cfg(feature = "zerocopy")]
+mod zerocopy;
@@ -0,0 +1,48 @@
+use crate::Vector2;
+use ordered_float::{FloatIsNan, NotNan, OrderedFloat};
+
+// Vector2<OrderedFloat<_>> and Vector2<NotNan<_>> get arithmetic, dot, cross and length_squared
+// from the generic impls; convert to plain floats for the methods that need `FloatAlone`.
+macro_rules! ordered {
+    ($($t:ty)+) => {$(
+        /// ```
+        /// # use vecto::Vector2;
+        /// # use std::collections::BTreeMap;
+        /// use ordered_float::{NotNan, OrderedFloat};
+        #[doc = concat!("let v = Vector2::<", stringify!($t), ">::new(3.0, 4.0);")]
+        /// let mut cache = BTreeMap::new();
+        /// cache.insert(Vector2::<OrderedFloat<_>>::from(v), "hit");
+        /// assert_eq!(cache.get(&v.into()), Some(&"hit"));
+        /// let key = Vector2::<NotNan<_>>::try_from(v).unwrap();
+        /// assert_eq!(key.length_squared(), NotNan::new(25.0).unwrap());
+        #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::from(key).length(), 5.0);")]
+        #[doc = concat!("assert!(Vector2::<NotNan<_>>::try_from(Vector2::<", stringify!($t), ">::new(0.0, ", stringify!($t), "::NAN)).is_err());")]
+        /// ```
+        impl From<Vector2<$t>> for Vector2<OrderedFloat<$t>> {
+            fn from(value: Vector2<$t>) -> Self {
+                value.map(OrderedFloat)
+            }
+        }
+
+        impl From<Vector2<OrderedFloat<$t>>> for Vector2<$t> {
+            fn from(value: Vector2<OrderedFloat<$t>>) -> Self {
+                value.map(|c| c.0)
+            }
+        }
+
+        impl TryFrom<Vector2<$t>> for Vector2<NotNan<$t>> {
+            type Error = FloatIsNan;
+            /// Fails if either component is NaN.
+            fn try_from(value: Vector2<$t>) -> Result<Self, Self::Error> {
+                Ok(Self::new(NotNan::new(value.x)?, NotNan::new(value.y)?))
+            }
+        }
+
+        impl From<Vector2<NotNan<$t>>> for Vector2<$t> {
+            fn from(value: Vector2<NotNan<$t>>) -> Self {
+                value.map(NotNan::into_inner)
+            }
+        }
+    )+};
+}
+ordered!(f32 f64);
@@ -0,0 +1,38 @@
+use crate::Vector2;
+use pyo3::{
+    types::PyAnyMethods, Bound, FromPyObject, IntoPy, PyAny, PyObject, PyResult, Python, ToPyObject,
+};
+
+/// Extracts from any sequence of two floats: tuples, lists, or numpy arrays.
+/// ```
+/// # use vecto::Vector2;
+/// # use pyo3::{Python, types::PyAnyMethods};
+/// pyo3::prepare_freethreaded_python();
+/// Python::with_gil(|py| {
+///     let v: Vector2<f64> = py.eval_bound("[1, 2.5]", None, None)?.extract()?;
+///     assert_eq!(v, Vector2::new(1.0, 2.5));
+///     let t: (f64, f64) = pyo3::IntoPy::into_py(v, py).extract(py)?;
+///     assert_eq!(t, (1.0, 2.5));
+///     assert!(py.eval_bound("(1, 2, 3)", None, None)?.extract::<Vector2<f64>>().is_err());
+///     Ok::<(), pyo3::PyErr>(())
+/// })?;
+/// # Ok::<(), pyo3::PyErr>(())
+/// ```
+impl<'py> FromPyObject<'py> for Vector2<f64> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        ob.extract::<[f64; 2]>().map(Self::from)
+    }
+}
+
+/// Converts to a tuple, `(x, y)`.
+impl IntoPy<PyObject> for Vector2<f64> {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        (self.x, self.y).into_py(py)
+    }
+}
+
+impl ToPyObject for Vector2<f64> {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        (self.x, self.y).to_object(py)
+    }
+}
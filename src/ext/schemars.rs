@@ -0,0 +1,51 @@
+use crate::Vector2;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use std::borrow::Cow;
+
+/// Describes every form the deserializer accepts: `{"x": .., "y": ..}`, `[x, y]`, a single number for both, or `"x,y"`.
+/// ```
+/// # use vecto::Vec2;
+/// let schema = schemars::schema_for!(Vec2);
+/// let forms = schema.get("anyOf").unwrap().as_array().unwrap();
+/// assert_eq!(forms.len(), 4);
+/// assert_eq!(forms[0]["required"], serde_json::json!(["x", "y"]));
+/// assert_eq!(forms[1]["type"], "array");
+/// assert_eq!(forms[2]["type"], "number");
+/// assert_eq!(forms[3]["type"], "string");
+/// ```
+impl<T: JsonSchema> JsonSchema for Vector2<T> {
+    fn schema_name() -> Cow<'static, str> {
+        format!("Vector2_for_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("vecto::Vector2<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let component = generator.subschema_for::<T>();
+        json_schema!({
+            "anyOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "x": component,
+                        "y": component,
+                    },
+                    "required": ["x", "y"],
+                },
+                {
+                    "type": "array",
+                    "items": component,
+                    "minItems": 2,
+                    "maxItems": 2,
+                },
+                component,
+                {
+                    "type": "string",
+                    "pattern": "^[^,]*,[^,]*$",
+                },
+            ],
+        })
+    }
+}
@@ -0,0 +1,34 @@
+use crate::Vector2;
+use speedy::{Context, Readable, Reader, Writable, Writer};
+
+/// Reads X, then Y.
+/// ```
+/// # use vecto::Vec2;
+/// use speedy::{Readable, Writable};
+/// let bytes = Vec2::new(1.0, 2.0).write_to_vec()?;
+/// assert_eq!(bytes.len(), 8);
+/// assert_eq!(Vec2::read_from_buffer(&bytes)?, Vec2::new(1.0, 2.0));
+/// # Ok::<(), speedy::Error>(())
+/// ```
+impl<'a, C: Context, T: Readable<'a, C>> Readable<'a, C> for Vector2<T> {
+    fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        let x = reader.read_value()?;
+        Ok(Self::new(x, reader.read_value()?))
+    }
+
+    fn minimum_bytes_needed() -> usize {
+        T::minimum_bytes_needed() * 2
+    }
+}
+
+/// Writes X, then Y.
+impl<C: Context, T: Writable<C>> Writable<C> for Vector2<T> {
+    fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        self.x.write_to(writer)?;
+        self.y.write_to(writer)
+    }
+
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        Ok(self.x.bytes_needed()? + self.y.bytes_needed()?)
+    }
+}
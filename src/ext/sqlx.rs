@@ -0,0 +1,55 @@
+use crate::Vector2;
+use sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres},
+    Decode, Encode, Type,
+};
+
+/// Maps to the Postgres `POINT` type.
+impl Type<Postgres> for Vector2<f64> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("point")
+    }
+}
+
+impl PgHasArrayType for Vector2<f64> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_point")
+    }
+}
+
+impl Encode<'_, Postgres> for Vector2<f64> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.extend_from_slice(&self.x.to_be_bytes());
+        buf.extend_from_slice(&self.y.to_be_bytes());
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Vector2<f64> {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let bytes: [u8; 16] = value
+                    .as_bytes()?
+                    .try_into()
+                    .map_err(|_| "POINT should be 16 bytes")?;
+                let (x, y) = bytes.split_at(8);
+                Ok(Self::new(
+                    f64::from_be_bytes(x.try_into()?),
+                    f64::from_be_bytes(y.try_into()?),
+                ))
+            }
+            // (x,y)
+            PgValueFormat::Text => {
+                let (x, y) = value
+                    .as_str()?
+                    .trim_matches(|c: char| c == '(' || c == ')' || c.is_whitespace())
+                    .split_once(',')
+                    .ok_or("POINT should be (x,y)")?;
+                Ok(Self::new(x.trim().parse()?, y.trim().parse()?))
+            }
+        }
+    }
+}
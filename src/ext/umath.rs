@@ -0,0 +1,43 @@
+use crate::Vector2;
+use umath::FFloat;
+
+// Vector2<FFloat<_>> is NaN-free, so it is `Ord` and works as a `BTreeMap` key, and it gets arithmetic from the generic impls.
+macro_rules! fast {
+    ($($t:ty)+) => {$(
+        /// ```
+        /// # use vecto::Vector2;
+        /// # use std::collections::BTreeMap;
+        /// use umath::FFloat;
+        #[doc = concat!("let v = Vector2::<", stringify!($t), ">::new(3.0, 4.0);")]
+        /// let key = Vector2::<FFloat<_>>::from(v);
+        /// let mut visited = BTreeMap::new();
+        /// visited.insert(key, "start");
+        /// visited.insert(Vector2::from(Vector2::new(1.0, 9.0)), "end");
+        /// assert_eq!(visited.get(&key), Some(&"start"));
+        /// assert!(visited.keys().next().unwrap() < &key); // ordered by x, then y
+        /// let moved = key + Vector2::from(Vector2::new(1.0, 1.0));
+        #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::from(moved), Vector2::new(4.0, 5.0));")]
+        #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::from(key).length(), 5.0);")]
+        /// ```
+        impl From<Vector2<$t>> for Vector2<FFloat<$t>> {
+            /// # Panics
+            ///
+            /// If either component is NaN or infinite.
+            fn from(value: Vector2<$t>) -> Self {
+                assert!(
+                    value.x.is_finite() && value.y.is_finite(),
+                    "{value:?} is not finite"
+                );
+                // SAFETY: checked above
+                value.map(|c| unsafe { FFloat::new(c) })
+            }
+        }
+
+        impl From<Vector2<FFloat<$t>>> for Vector2<$t> {
+            fn from(value: Vector2<FFloat<$t>>) -> Self {
+                value.map(|c| *c)
+            }
+        }
+    )+};
+}
+fast!(f32 f64);
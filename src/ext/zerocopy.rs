@@ -0,0 +1,18 @@
+use crate::Vector2;
+use zerocopy::AsBytes;
+
+// `FromZeroes` and `FromBytes` are derived on `Vector2`; the derive can't check generic structs for padding.
+
+/// ```
+/// # use vecto::Vec2;
+/// use zerocopy::{AsBytes, FromBytes};
+/// let points = [Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)];
+/// let bytes = points.as_bytes();
+/// assert_eq!(bytes.len(), 16);
+/// assert_eq!(Vec2::slice_from(bytes), Some(&points[..]));
+/// ```
+// SAFETY: `Vector2` is repr(C) with two fields of the same type `T`, so the second starts right where the first ends
+// and the struct ends right after it: there is no padding, and every byte is a byte of an `AsBytes` field.
+unsafe impl<T: AsBytes> AsBytes for Vector2<T> {
+    fn only_derive_is_allowed_to_implement_this_trait() {}
+}
@@ -0,0 +1,304 @@
+//! Grids of values sampled with bilinear filtering.
+use crate::Vec2;
+use core::ops::{Add, Mul, Sub};
+
+/// What sampling outside of a [`Field2`] does.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Boundary {
+    /// Use the nearest edge cell.
+    #[default]
+    Clamp,
+    /// Wrap around to the other side, for tiling fields.
+    Wrap,
+}
+
+/// A `width` × `height` grid of values, with cell `(x, y)` located at position `(x, y)`.
+/// ```
+/// # use vecto::{Vec2, field::{Boundary, VectorField2}};
+/// // a field flowing right in the top row and down in the bottom row
+/// let field = VectorField2::from_fn(2, 2, |_, y| if y == 0 { Vec2::RIGHT } else { Vec2::DOWN });
+/// assert_eq!(field.sample(Vec2::new(0.5, 0.5)), Vec2::new(0.5, 0.5));
+/// assert_eq!(field.sample(Vec2::new(-3.0, 7.0)), Vec2::DOWN); // clamped
+/// let field = field.with_boundary(Boundary::Wrap);
+/// assert_eq!(field.sample(Vec2::new(0.0, 1.5)), Vec2::new(0.5, 0.5)); // halfway back to the top row
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct Field2<T> {
+    width: usize,
+    height: usize,
+    data: Vec<T>,
+    boundary: Boundary,
+}
+
+/// A grid of [`Vec2`]s, e.g. a flow field.
+pub type VectorField2 = Field2<Vec2>;
+
+/// A grid of [`f32`]s, e.g. a pressure or height field.
+pub type ScalarField2 = Field2<f32>;
+
+impl<T> Field2<T> {
+    /// Construct a new [`Field2`], calling `f` with the `x` and `y` of each cell.
+    ///
+    /// # Panics
+    ///
+    /// If either dimension is zero.
+    #[must_use]
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        assert!(width > 0 && height > 0, "field must have at least one cell");
+        let data = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Self {
+            width,
+            height,
+            data,
+            boundary: Boundary::default(),
+        }
+    }
+
+    /// Sets how sampling outside the field behaves.
+    #[must_use = "Does not modify in place."]
+    pub fn with_boundary(self, boundary: Boundary) -> Self {
+        Self { boundary, ..self }
+    }
+
+    /// Returns the number of columns.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cells, row by row.
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns the cell at `(x, y)`, if it is within the field.
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.data[y * self.width + x])
+    }
+
+    /// Returns the cell at `(x, y)` mutably, if it is within the field.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.data[y * self.width + x])
+    }
+
+    /// Applies `f` to every cell.
+    pub fn map_in_place(&mut self, f: impl FnMut(&mut T)) {
+        self.data.iter_mut().for_each(f);
+    }
+
+    /// Combines every cell with the matching cell of `other`.
+    /// ```
+    /// # use vecto::{Vec2, field::VectorField2};
+    /// let mut wind = VectorField2::from_fn(4, 4, |_, _| Vec2::RIGHT);
+    /// let gusts = VectorField2::from_fn(4, 4, |x, _| Vec2::DOWN * x as f32);
+    /// wind.zip_in_place(&gusts, |w, g| *w += *g);
+    /// assert_eq!(wind.get(2, 0), Some(&Vec2::new(1.0, 2.0)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the fields have different dimensions.
+    pub fn zip_in_place<U>(&mut self, other: &Field2<U>, mut f: impl FnMut(&mut T, &U)) {
+        assert!(
+            self.width == other.width && self.height == other.height,
+            "fields must have the same dimensions"
+        );
+        self.data
+            .iter_mut()
+            .zip(&other.data)
+            .for_each(|(a, b)| f(a, b));
+    }
+
+    /// Resolves a possibly out of bounds cell according to the [`Boundary`].
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_possible_wrap
+    )]
+    fn cell(&self, x: isize, y: isize) -> &T {
+        let (w, h) = (self.width as isize, self.height as isize);
+        let (x, y) = match self.boundary {
+            Boundary::Clamp => (x.clamp(0, w - 1), y.clamp(0, h - 1)),
+            Boundary::Wrap => (x.rem_euclid(w), y.rem_euclid(h)),
+        };
+        &self.data[y as usize * self.width + x as usize]
+    }
+}
+
+impl<T: Clone> Field2<T> {
+    /// Construct a new [`Field2`] with every cell set to `value`.
+    ///
+    /// # Panics
+    ///
+    /// If either dimension is zero.
+    #[must_use]
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self::from_fn(width, height, |_, _| value.clone())
+    }
+}
+
+impl<T: Copy + Add<T, Output = T> + Mul<f32, Output = T>> Field2<T> {
+    /// Bilinearly samples the field at `pos`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn sample(&self, pos: Vec2) -> T {
+        let base = pos.floor();
+        let f = pos - base;
+        let (x, y) = (base.x as isize, base.y as isize);
+        let top = *self.cell(x, y) * (1.0 - f.x) + *self.cell(x + 1, y) * f.x;
+        let bottom = *self.cell(x, y + 1) * (1.0 - f.x) + *self.cell(x + 1, y + 1) * f.x;
+        top * (1.0 - f.y) + bottom * f.y
+    }
+}
+
+impl<T: Copy + Sub<T, Output = T> + Mul<f32, Output = T>> Field2<T> {
+    /// Builds a new field from the differences across every cell, `f(d/dx, d/dy)`.
+    #[allow(clippy::cast_possible_wrap)]
+    fn differentiate<U>(&self, f: impl Fn(T, T) -> U) -> Field2<U> {
+        Field2::from_fn(self.width, self.height, |x, y| {
+            let (dx, dy) = self.derivative(x as isize, y as isize);
+            f(dx, dy)
+        })
+        .with_boundary(self.boundary)
+    }
+
+    /// Returns the change per cell across `(x, y)`, along x and along y.
+    ///
+    /// These are central differences, except where [`Boundary::Clamp`] holds a neighbour on the edge:
+    /// there they are one-sided, as the central difference would only see half the slope.
+    #[allow(clippy::cast_possible_wrap, clippy::cast_precision_loss)]
+    fn derivative(&self, x: isize, y: isize) -> (T, T) {
+        let (w, h) = (self.width as isize, self.height as isize);
+        let (x, y, (left, right), (up, down)) = match self.boundary {
+            Boundary::Clamp => {
+                let (x, y) = (x.clamp(0, w - 1), y.clamp(0, h - 1));
+                let (left, right) = ((x - 1).max(0), (x + 1).min(w - 1));
+                let (up, down) = ((y - 1).max(0), (y + 1).min(h - 1));
+                (x, y, (left, right), (up, down))
+            }
+            Boundary::Wrap => (x, y, (x - 1, x + 1), (y - 1, y + 1)),
+        };
+        // a single row or column has no neighbours to differ from
+        let per_cell = |span: isize| if span == 0 { 0.0 } else { 1.0 / span as f32 };
+        (
+            (*self.cell(right, y) - *self.cell(left, y)) * per_cell(right - left),
+            (*self.cell(x, down) - *self.cell(x, up)) * per_cell(down - up),
+        )
+    }
+}
+
+impl ScalarField2 {
+    /// Returns the gradient of the field, pointing towards increasing values, computed with central differences
+    /// (one-sided on [clamped](Boundary::Clamp) edges).
+    /// ```
+    /// # use vecto::{Vec2, field::ScalarField2};
+    /// let slope = ScalarField2::from_fn(4, 4, |x, y| x as f32 * 2.0 + y as f32);
+    /// assert_eq!(slope.gradient().get(1, 1), Some(&Vec2::new(2.0, 1.0)));
+    /// // the edges see the same slope
+    /// assert_eq!(slope.gradient().get(0, 3), Some(&Vec2::new(2.0, 1.0)));
+    /// ```
+    #[must_use]
+    pub fn gradient(&self) -> VectorField2 {
+        self.differentiate(Vec2::new)
+    }
+
+    /// Returns the gradient at `pos`, bilinearly filtered from the [`ScalarField2::gradient`] of the four surrounding cells,
+    /// so it changes smoothly between cells (unlike the derivative of [`Field2::sample`]).
+    ///
+    /// For a heightmap with cells `cell_size` apart: `pos` is in world units (cell `(x, y)` is at `(x, y) * cell_size`),
+    /// and the result is the rise in height per world unit. Walking against it goes downhill.
+    /// ```
+    /// # use vecto::{Vec2, field::ScalarField2};
+    /// let hill = ScalarField2::from_fn(8, 8, |x, y| x as f32 + 3.0 * y as f32);
+    /// assert_eq!(hill.gradient_at(Vec2::new(5.0, 7.0), 2.0), Vec2::new(0.5, 1.5));
+    /// // on the edge too
+    /// assert_eq!(hill.gradient_at(Vec2::new(0.0, 14.0), 2.0), Vec2::new(0.5, 1.5));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn gradient_at(&self, pos: Vec2, cell_size: f32) -> Vec2 {
+        let pos = pos / cell_size;
+        let base = pos.floor();
+        let f = pos - base;
+        let (x, y) = (base.x as isize, base.y as isize);
+        let at = |x: isize, y: isize| {
+            let (dx, dy) = self.derivative(x, y);
+            Vec2::new(dx, dy)
+        };
+        let top = at(x, y) * (1.0 - f.x) + at(x + 1, y) * f.x;
+        let bottom = at(x, y + 1) * (1.0 - f.x) + at(x + 1, y + 1) * f.x;
+        (top * (1.0 - f.y) + bottom * f.y) / cell_size
+    }
+
+    /// Returns the steepness at `pos` as an angle from flat, in radians: 0 for flat ground, approaching π/2 for a cliff.
+    ///
+    /// See [`ScalarField2::gradient_at`] for the units.
+    /// ```
+    /// # use vecto::{Vec2, field::ScalarField2};
+    /// let ramp = ScalarField2::from_fn(4, 4, |x, _| x as f32);
+    /// assert!((ramp.slope_at(Vec2::new(1.5, 1.5), 1.0) - core::f32::consts::FRAC_PI_4).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn slope_at(&self, pos: Vec2, cell_size: f32) -> f32 {
+        self.gradient_at(pos, cell_size).length().atan()
+    }
+
+    /// Returns the unit surface normal at `pos`, as `[x, y, up]`, treating the values as heights above the plane.
+    ///
+    /// The `x` and `y` parts point downhill, so they are the direction something would slide.
+    /// See [`ScalarField2::gradient_at`] for the units.
+    /// ```
+    /// # use vecto::{Vec2, field::ScalarField2};
+    /// let flat = ScalarField2::filled(4, 4, 2.0);
+    /// assert_eq!(flat.normal_at(Vec2::new(1.0, 1.0), 1.0), [0.0, 0.0, 1.0]);
+    /// let ramp = ScalarField2::from_fn(4, 4, |x, _| x as f32);
+    /// let [x, y, up] = ramp.normal_at(Vec2::new(1.5, 1.5), 1.0);
+    /// assert!(x < 0.0 && y == 0.0 && (x.hypot(up) - 1.0).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn normal_at(&self, pos: Vec2, cell_size: f32) -> [f32; 3] {
+        let g = self.gradient_at(pos, cell_size);
+        let length = (g.length_squared() + 1.0).sqrt();
+        [-g.x / length, -g.y / length, 1.0 / length]
+    }
+}
+
+impl VectorField2 {
+    /// Returns the divergence of the field (how much it flows out of each cell), computed with central differences
+    /// (one-sided on [clamped](Boundary::Clamp) edges).
+    /// ```
+    /// # use vecto::{Vec2, field::VectorField2};
+    /// let explosion = VectorField2::from_fn(5, 5, |x, y| Vec2::new(x as f32, y as f32) - Vec2::splat(2.0));
+    /// assert_eq!(explosion.divergence().get(2, 2), Some(&2.0));
+    /// ```
+    #[must_use]
+    pub fn divergence(&self) -> ScalarField2 {
+        self.differentiate(|dx, dy| dx.x + dy.y)
+    }
+
+    /// Returns the (scalar, 2D) curl of the field (how much it rotates around each cell), computed with central differences
+    /// (one-sided on [clamped](Boundary::Clamp) edges).
+    ///
+    /// Y is down, so positive curl is clockwise on screen.
+    /// ```
+    /// # use vecto::{Vec2, field::VectorField2};
+    /// let vortex = VectorField2::from_fn(5, 5, |x, y| (Vec2::new(x as f32, y as f32) - Vec2::splat(2.0)).orthogonal());
+    /// assert_eq!(vortex.curl().get(2, 2), Some(&-2.0));
+    /// assert_eq!(vortex.divergence().get(2, 2), Some(&0.0));
+    /// ```
+    #[must_use]
+    pub fn curl(&self) -> ScalarField2 {
+        self.differentiate(|dx, dy| dx.y - dy.x)
+    }
+}
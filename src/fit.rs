@@ -0,0 +1,221 @@
+//! Least-squares fitting of shapes to points.
+use crate::{
+    batch,
+    shape::{Circle, Line2},
+    Vec2,
+};
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+/// A fitted shape, and how well it fits.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Fit<S> {
+    /// The shape.
+    pub shape: S,
+    /// The root mean square distance from the points to the shape; zero for a perfect fit.
+    pub rms_error: f32,
+}
+
+/// Returns the line minimizing the sum of squared (perpendicular) distances to the points, or [`None`] if there are fewer than two points.
+///
+/// This is the total least squares line: through the [mean](batch::mean), along the principal axis of the [covariance](batch::covariance),
+/// so unlike `y = ax + b` regression it handles vertical lines.
+/// ```
+/// # use vecto::{Vec2, fit::fit_line};
+/// let stroke = [Vec2::new(1.0, 0.0), Vec2::new(1.1, 5.0), Vec2::new(0.9, 10.0), Vec2::new(1.0, 15.0)];
+/// let fit = fit_line(&stroke).unwrap();
+/// assert!(fit.shape.direction.x.abs() < 0.02);
+/// assert!(fit.rms_error < 0.1); // straight enough
+/// ```
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn fit_line(points: &[Vec2]) -> Option<Fit<Line2>> {
+    if points.len() < 2 {
+        return None;
+    }
+    let mean = batch::mean(points)?;
+    let (direction, _) = batch::covariance(points)?.eigenvectors();
+    let shape = Line2 {
+        point: mean,
+        direction,
+    };
+    let squared = points
+        .iter()
+        .map(|&p| shape.distance_to(p).powi(2))
+        .sum::<f32>();
+    Some(Fit {
+        shape,
+        rms_error: (squared / points.len() as f32).sqrt(),
+    })
+}
+
+/// Returns the circle minimizing the algebraic distance to the points (a Kåsa fit), or [`None`] if there are fewer than three points or they are collinear.
+///
+/// Fast and exact for points on a circle. With noise, it underestimates the radius of points covering only a short arc.
+/// ```
+/// # use vecto::{Vec2, fit::fit_circle};
+/// let gesture: Vec<_> = (0..12).map(|i| Vec2::new(3.0, -1.0) + Vec2::from_angle(i as f32 * 0.5) * 2.0).collect();
+/// let fit = fit_circle(&gesture).unwrap();
+/// assert!((fit.shape.center - Vec2::new(3.0, -1.0)).length() < 1e-4);
+/// assert!((fit.shape.radius - 2.0).abs() < 1e-4);
+/// assert!(fit.rms_error < 1e-4);
+/// assert_eq!(fit_circle(&[Vec2::ZERO, Vec2::RIGHT, Vec2::new(2.0, 0.0)]), None);
+/// ```
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn fit_circle(points: &[Vec2]) -> Option<Fit<Circle>> {
+    if points.len() < 3 {
+        return None;
+    }
+    let mean = batch::mean(points)?;
+    // moments about the mean, for numerical stability
+    let (mut uu, mut uv, mut vv, mut rhs) = (0.0, 0.0, 0.0, Vec2::ZERO);
+    for &p in points {
+        let d = p - mean;
+        uu += d.x * d.x;
+        uv += d.x * d.y;
+        vv += d.y * d.y;
+        rhs += d * d.length_squared() * 0.5;
+    }
+    // solve [uu uv; uv vv] * center = rhs
+    let determinant = uu * vv - uv * uv;
+    if determinant.abs() <= f32::EPSILON * (uu * vv).max(f32::MIN_POSITIVE) {
+        return None;
+    }
+    let center = Vec2::new(vv * rhs.x - uv * rhs.y, uu * rhs.y - uv * rhs.x) / determinant;
+    let n = points.len() as f32;
+    let shape = Circle::new(
+        mean + center,
+        (center.length_squared() + (uu + vv) / n).sqrt(),
+    );
+    let squared = points
+        .iter()
+        .map(|&p| shape.signed_distance_to(p).powi(2))
+        .sum::<f32>();
+    Some(Fit {
+        shape,
+        rms_error: (squared / n).sqrt(),
+    })
+}
+
+/// A shape that can be fitted to points, for [`ransac`].
+pub trait Model: Sized {
+    /// How many points it takes to determine the shape.
+    const MINIMUM_POINTS: usize;
+
+    /// Fits the shape to the points, or returns [`None`] if they don't determine one.
+    fn fit(points: &[Vec2]) -> Option<Fit<Self>>;
+
+    /// Returns the distance from `point` to the shape.
+    fn distance_to(&self, point: Vec2) -> f32;
+}
+
+impl Model for Line2 {
+    const MINIMUM_POINTS: usize = 2;
+
+    fn fit(points: &[Vec2]) -> Option<Fit<Self>> {
+        fit_line(points)
+    }
+
+    fn distance_to(&self, point: Vec2) -> f32 {
+        Line2::distance_to(self, point)
+    }
+}
+
+impl Model for Circle {
+    const MINIMUM_POINTS: usize = 3;
+
+    fn fit(points: &[Vec2]) -> Option<Fit<Self>> {
+        fit_circle(points)
+    }
+
+    fn distance_to(&self, point: Vec2) -> f32 {
+        self.signed_distance_to(point).abs()
+    }
+}
+
+/// The result of [`ransac`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct Consensus<M> {
+    /// The shape, refitted to all of its inliers.
+    pub model: M,
+    /// Whether each point is within the threshold of the shape.
+    pub inliers: Vec<bool>,
+}
+
+impl<M> Consensus<M> {
+    /// Returns the number of inliers.
+    #[must_use]
+    pub fn inlier_count(&self) -> usize {
+        self.inliers.iter().filter(|&&x| x).count()
+    }
+}
+
+/// Robustly fits a shape to points with outliers, using [RANSAC](https://en.wikipedia.org/wiki/Random_sample_consensus).
+///
+/// Fits `iterations` shapes to random minimal samples and keeps the one with the most points within `threshold` of it,
+/// then refits it to those points with least squares.
+/// Returns [`None`] if no sample determined a shape (e.g. too few points).
+/// ```
+/// # use vecto::{Vec2, shape::Line2, fit::ransac};
+/// # use rand::{SeedableRng, rngs::StdRng};
+/// let mut points: Vec<_> = (0..20).map(|i| Vec2::new(i as f32, 2.0 * i as f32 + 1.0)).collect();
+/// points.extend([Vec2::new(3.0, 40.0), Vec2::new(15.0, -9.0), Vec2::new(8.0, 80.0)]);
+/// let found = ransac::<Line2>(&points, 0.1, 50, &mut StdRng::seed_from_u64(1)).unwrap();
+/// assert_eq!(found.inlier_count(), 20);
+/// assert_eq!(&found.inliers[20..], [false; 3]);
+/// assert!(found.model.distance_to(Vec2::new(100.0, 201.0)) < 1e-2);
+/// ```
+#[cfg(feature = "rand")]
+pub fn ransac<M: Model>(
+    points: &[Vec2],
+    threshold: f32,
+    iterations: usize,
+    rng: &mut impl Rng,
+) -> Option<Consensus<M>> {
+    if points.len() < M::MINIMUM_POINTS {
+        return None;
+    }
+    let inliers = |model: &M| {
+        points
+            .iter()
+            .map(|&p| model.distance_to(p) <= threshold)
+            .collect::<Vec<_>>()
+    };
+    let mut best: Option<(M, usize)> = None;
+    let mut indices = Vec::with_capacity(M::MINIMUM_POINTS);
+    let mut sample = Vec::with_capacity(M::MINIMUM_POINTS);
+    for _ in 0..iterations {
+        indices.clear();
+        while indices.len() < M::MINIMUM_POINTS {
+            let i = rng.gen_range(0..points.len());
+            if !indices.contains(&i) {
+                indices.push(i);
+            }
+        }
+        sample.clear();
+        sample.extend(indices.iter().map(|&i| points[i]));
+        let Some(Fit { shape, .. }) = M::fit(&sample) else {
+            continue;
+        };
+        let count = points
+            .iter()
+            .filter(|&&p| shape.distance_to(p) <= threshold)
+            .count();
+        if best.as_ref().is_none_or(|&(_, most)| count > most) {
+            best = Some((shape, count));
+        }
+    }
+    let (model, _) = best?;
+    let mask = inliers(&model);
+    let chosen: Vec<_> = points
+        .iter()
+        .zip(&mask)
+        .filter_map(|(&p, &inlier)| inlier.then_some(p))
+        .collect();
+    let model = M::fit(&chosen).map_or(model, |fit| fit.shape);
+    Some(Consensus {
+        inliers: inliers(&model),
+        model,
+    })
+}
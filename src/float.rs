@@ -0,0 +1,153 @@
+//! Component-wise wrappers of the inherent float methods, which aren't available generically.
+use crate::Vector2;
+
+macro_rules! float {
+    ($t:ty) => {
+        impl Vector2<$t> {
+            /// Returns true if every component is within `0.00001` of zero.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("assert!(Vector2::<", stringify!($t), ">::new(1e-7, -1e-7).is_zero_approx());")]
+            #[doc = concat!("assert!(!Vector2::<", stringify!($t), ">::new(0.0, 0.1).is_zero_approx());")]
+            /// ```
+            #[must_use]
+            #[inline]
+            pub fn is_zero_approx(self) -> bool {
+                self.is_zero_approx_with(0.00001)
+            }
+
+            /// Returns true if every component is within `tolerance` of zero.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("let velocity = Vector2::<", stringify!($t), ">::new(0.004, -0.002);")]
+            /// // put the body to sleep once it has (nearly) stopped
+            /// assert!(velocity.is_zero_approx_with(0.01));
+            /// ```
+            #[must_use]
+            #[inline]
+            pub fn is_zero_approx_with(self, tolerance: $t) -> bool {
+                self.approx_eq_with(Self::splat(0.0), tolerance)
+            }
+
+            /// Returns true if every component is within `tolerance` of the matching component of `other`.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("let v = Vector2::<", stringify!($t), ">::new(1.0, 2.0);")]
+            /// assert!(v.approx_eq_with(Vector2::new(1.05, 1.95), 0.1));
+            /// assert!(!v.approx_eq_with(Vector2::new(1.05, 1.95), 0.01));
+            /// ```
+            #[must_use]
+            #[inline]
+            pub fn approx_eq_with(self, other: Self, tolerance: $t) -> bool {
+                // exact equality first, for infinities
+                #[allow(clippy::float_cmp)]
+                let close = |a: $t, b: $t| a == b || (a - b).abs() < tolerance;
+                close(self.x, other.x) && close(self.y, other.y)
+            }
+
+            /// Returns the component-wise absolute difference `|self - with|`.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("let v = Vector2::<", stringify!($t), ">::new(3.0, -1.0);")]
+            /// assert_eq!(v.abs_diff(Vector2::new(5.0, 1.0)), Vector2::new(2.0, 2.0));
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn abs_diff(self, with: Self) -> Self {
+                Self::new((self.x - with.x).abs(), (self.y - with.y).abs())
+            }
+
+            /// Returns a new vector with the magnitudes of `self` and the signs of `sign`.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("let v = Vector2::<", stringify!($t), ">::new(1.0, -2.0);")]
+            /// assert_eq!(v.copysign(Vector2::new(-0.0, 5.0)), Vector2::new(-1.0, 2.0));
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn copysign(self, sign: Self) -> Self {
+                Self::new(self.x.copysign(sign.x), self.y.copysign(sign.y))
+            }
+
+            /// Returns `self * a + b`, computed with a single rounding error (fused multiply-add).
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("let v = Vector2::<", stringify!($t), ">::new(1.0, 2.0);")]
+            /// assert_eq!(v.mul_add(Vector2::splat(3.0), Vector2::new(1.0, 0.0)), Vector2::new(4.0, 6.0));
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn mul_add(self, a: Self, b: Self) -> Self {
+                Self::new(self.x.mul_add(a.x, b.x), self.y.mul_add(a.y, b.y))
+            }
+
+            /// Returns the component-wise reciprocal, `1 / self`.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::new(2.0, 4.0).recip(), Vector2::new(0.5, 0.25));")]
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn recip(self) -> Self {
+                self.map(<$t>::recip)
+            }
+
+            /// Raises each component to the floating point power `n`.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::new(4.0, 9.0).powf(0.5), Vector2::new(2.0, 3.0));")]
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn powf(self, n: $t) -> Self {
+                self.map(|c| c.powf(n))
+            }
+
+            /// Raises each component to the integer power `n`.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::new(2.0, -3.0).powi(2), Vector2::new(4.0, 9.0));")]
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn powi(self, n: i32) -> Self {
+                self.map(|c| c.powi(n))
+            }
+
+            /// Returns the square root of each component.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::new(4.0, 16.0).sqrt(), Vector2::new(2.0, 4.0));")]
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn sqrt(self) -> Self {
+                self.map(<$t>::sqrt)
+            }
+
+            /// Returns `e^c` for each component `c`.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::new(0.0, 0.0).exp(), Vector2::splat(1.0));")]
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn exp(self) -> Self {
+                self.map(<$t>::exp)
+            }
+
+            /// Returns the natural logarithm of each component.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::new(1.0, 1.0).ln(), Vector2::splat(0.0));")]
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn ln(self) -> Self {
+                self.map(<$t>::ln)
+            }
+        }
+    };
+}
+float!(f32);
+float!(f64);
@@ -0,0 +1,132 @@
+use crate::{angle::wrap_angle, Transform2D, Vec2};
+use core::ops::Mul;
+
+/// A coordinate frame: an origin, a rotation and a uniform scale, such as a ship's position and heading.
+///
+/// Local coordinates are relative to the frame, world coordinates are relative to whatever the frame is placed in.
+/// ```
+/// # use vecto::{Frame2, Kinda, Vec2};
+/// # use std::f32::consts::FRAC_PI_2;
+/// // a ship at (10, 0), facing down
+/// let ship = Frame2::new(Vec2::new(10.0, 0.0), FRAC_PI_2);
+/// let cannon = Vec2::new(2.0, 0.0); // in front of the ship
+/// assert!(ship.to_world(cannon).approx_eq(Vec2::new(10.0, 2.0)));
+/// assert!(ship.to_local(Vec2::new(10.0, 2.0)).approx_eq(cannon));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Frame2 {
+    /// Where the frame's origin is.
+    pub origin: Vec2,
+    /// The rotation in radians. Y is down, so positive is clockwise on screen.
+    pub rotation: f32,
+    /// How much bigger one local unit is than one world unit.
+    pub scale: f32,
+}
+
+impl Default for Frame2 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Frame2 {
+    /// The frame matching the world: no translation, rotation or scale.
+    pub const IDENTITY: Self = Self::new(Vec2::ZERO, 0.0);
+
+    /// Construct a new unscaled [`Frame2`].
+    #[must_use]
+    #[inline]
+    pub const fn new(origin: Vec2, rotation: f32) -> Self {
+        Self {
+            origin,
+            rotation,
+            scale: 1.0,
+        }
+    }
+
+    /// Returns the frame with a new scale.
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub const fn with_scale(self, scale: f32) -> Self {
+        Self { scale, ..self }
+    }
+
+    /// Converts a point from local to world coordinates.
+    #[must_use]
+    #[inline]
+    pub fn to_world(&self, point: Vec2) -> Vec2 {
+        self.origin + self.to_world_direction(point)
+    }
+
+    /// Converts a point from world to local coordinates.
+    #[must_use]
+    #[inline]
+    pub fn to_local(&self, point: Vec2) -> Vec2 {
+        self.to_local_direction(point - self.origin)
+    }
+
+    /// Converts a direction or offset from local to world coordinates, rotating and scaling without translating.
+    #[must_use]
+    #[inline]
+    pub fn to_world_direction(&self, direction: Vec2) -> Vec2 {
+        (direction * self.scale).rotated(self.rotation)
+    }
+
+    /// Converts a direction or offset from world to local coordinates, rotating and scaling without translating.
+    #[must_use]
+    #[inline]
+    pub fn to_local_direction(&self, direction: Vec2) -> Vec2 {
+        direction.rotated(-self.rotation) / self.scale
+    }
+
+    /// Returns the frame that undoes this one, so that `f.inverse().to_world(p) == f.to_local(p)`.
+    /// ```
+    /// # use vecto::{Frame2, Kinda, Vec2};
+    /// let f = Frame2::new(Vec2::new(3.0, -1.0), 0.7).with_scale(2.0);
+    /// let p = Vec2::new(5.0, 4.0);
+    /// assert!(f.inverse().to_world(p).approx_eq(f.to_local(p)));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn inverse(self) -> Self {
+        Self {
+            origin: self.to_local_direction(-self.origin),
+            rotation: -self.rotation,
+            scale: 1.0 / self.scale,
+        }
+    }
+}
+
+impl Mul for Frame2 {
+    type Output = Self;
+
+    /// Places `rhs` inside `self`, so that `(a * b).to_world(p) == a.to_world(b.to_world(p))`.
+    /// ```
+    /// # use vecto::{Frame2, Kinda, Vec2};
+    /// let ship = Frame2::new(Vec2::new(10.0, 0.0), 1.0);
+    /// let turret = Frame2::new(Vec2::new(0.0, 2.0), 0.5); // on the ship
+    /// let p = Vec2::new(1.0, 0.0);
+    /// assert!((ship * turret).to_world(p).approx_eq(ship.to_world(turret.to_world(p))));
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            origin: self.to_world(rhs.origin),
+            rotation: wrap_angle(self.rotation + rhs.rotation),
+            scale: self.scale * rhs.scale,
+        }
+    }
+}
+
+impl From<Frame2> for Transform2D {
+    /// Returns the [`Transform2D`] that converts from local to world coordinates.
+    #[inline]
+    fn from(frame: Frame2) -> Self {
+        Self::new(
+            frame.to_world_direction(Vec2::new(1.0, 0.0)),
+            frame.to_world_direction(Vec2::new(0.0, 1.0)),
+            frame.origin,
+        )
+    }
+}
@@ -19,15 +19,79 @@ impl<T> From<[T; 2]> for Vector2<T> {
     }
 }
 
+/// The error returned when converting a slice whose length isn't 2 into a [`Vector2`].
+/// ```
+/// # use vecto::{TryFromSliceError, Vec2};
+/// assert_eq!(Vec2::try_from(&[1.0, 2.0][..]), Ok(Vec2::new(1.0, 2.0)));
+/// let e = Vec2::try_from(&[1.0, 2.0, 3.0][..]).unwrap_err();
+/// assert_eq!(e, TryFromSliceError::TooLong(3));
+/// assert_eq!(e.to_string(), "expected 2 elements, found 3");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TryFromSliceError {
+    /// The slice had fewer than 2 elements.
+    TooShort(usize),
+    /// The slice had more than 2 elements.
+    TooLong(usize),
+}
+
+impl TryFromSliceError {
+    fn new(len: usize) -> Self {
+        if len < 2 {
+            Self::TooShort(len)
+        } else {
+            Self::TooLong(len)
+        }
+    }
+
+    /// Returns the length of the slice.
+    #[must_use]
+    pub fn actual_len(self) -> usize {
+        match self {
+            Self::TooShort(len) | Self::TooLong(len) => len,
+        }
+    }
+}
+
+impl std::fmt::Display for TryFromSliceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected 2 elements, found {}", self.actual_len())
+    }
+}
+
+impl std::error::Error for TryFromSliceError {}
+
 impl<T: Copy> TryFrom<&[T]> for Vector2<T> {
-    type Error = ();
+    type Error = TryFromSliceError;
     /// If the slice len is 2, constructs a new vec.
     fn try_from(value: &[T]) -> Result<Self, Self::Error> {
-        value
-            .len()
-            .eq(&2)
-            .then(|| Self::new(value[0], value[1]))
-            .ok_or(())
+        match *value {
+            [x, y] => Ok(Self::new(x, y)),
+            _ => Err(TryFromSliceError::new(value.len())),
+        }
+    }
+}
+
+impl<T: Copy> TryFrom<&mut [T]> for Vector2<T> {
+    type Error = TryFromSliceError;
+    /// If the slice len is 2, constructs a new vec.
+    fn try_from(value: &mut [T]) -> Result<Self, Self::Error> {
+        Self::try_from(&*value)
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for Vector2<T> {
+    type Error = TryFromSliceError;
+    /// If the vec len is 2, constructs a new vec from its elements.
+    /// ```
+    /// # use vecto::{TryFromSliceError, IVec2};
+    /// assert_eq!(IVec2::try_from(vec![1, 2]), Ok(IVec2::new(1, 2)));
+    /// assert_eq!(IVec2::try_from(vec![1]), Err(TryFromSliceError::TooShort(1)));
+    /// ```
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        <[T; 2]>::try_from(value)
+            .map(Self::from)
+            .map_err(|v| TryFromSliceError::new(v.len()))
     }
 }
 
@@ -37,3 +101,143 @@ impl<T> From<Vector2<T>> for (T, T) {
         (value.x, value.y)
     }
 }
+
+impl<T: Copy> From<&Vector2<T>> for (T, T) {
+    /// Tuplifys the vec, (x, y).
+    fn from(value: &Vector2<T>) -> Self {
+        (value.x, value.y)
+    }
+}
+
+impl<T: Copy> From<&Vector2<T>> for [T; 2] {
+    /// Arrayifys the vec, [x, y].
+    fn from(value: &Vector2<T>) -> Self {
+        [value.x, value.y]
+    }
+}
+
+impl<T> From<Vector2<T>> for [T; 2] {
+    /// Arrayifys the vec, [x, y].
+    fn from(value: Vector2<T>) -> Self {
+        value.to_array()
+    }
+}
+
+impl<T> AsRef<[T; 2]> for Vector2<T> {
+    fn as_ref(&self) -> &[T; 2] {
+        // SAFETY: repr(C) with two fields of the same type has the layout of [T; 2]
+        unsafe { &*core::ptr::from_ref(self).cast::<[T; 2]>() }
+    }
+}
+
+impl<T> AsMut<[T; 2]> for Vector2<T> {
+    fn as_mut(&mut self) -> &mut [T; 2] {
+        // SAFETY: see AsRef
+        unsafe { &mut *core::ptr::from_mut(self).cast::<[T; 2]>() }
+    }
+}
+
+impl<T> Vector2<T> {
+    /// Returns `[x, y]`.
+    /// ```
+    /// # use vecto::Vec2;
+    /// assert_eq!(Vec2::new(1.0, 2.0).to_array(), [1.0, 2.0]);
+    /// ```
+    #[must_use]
+    pub fn to_array(self) -> [T; 2] {
+        [self.x, self.y]
+    }
+
+    /// Returns the components as a slice, `[x, y]`.
+    /// ```
+    /// # use vecto::Vec2;
+    /// assert_eq!(Vec2::new(1.0, 2.0).as_slice(), &[1.0, 2.0]);
+    /// ```
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        self.as_ref()
+    }
+
+    /// Returns the components as a mutable slice, `[x, y]`.
+    /// ```
+    /// # use vecto::Vec2;
+    /// let mut v = Vec2::new(1.0, 2.0);
+    /// v.as_mut_slice()[1] = 5.0;
+    /// assert_eq!(v, Vec2::new(1.0, 5.0));
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.as_mut()
+    }
+}
+
+impl<T> Vector2<T> {
+    /// Reinterprets a slice of vectors as a slice of their components, `[x0, y0, x1, y1, ..]`, without copying.
+    /// ```
+    /// # use vecto::Vec2;
+    /// let points = [Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)];
+    /// assert_eq!(Vec2::cast_slice(&points), [1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    #[must_use]
+    pub fn cast_slice(slice: &[Self]) -> &[T] {
+        // SAFETY: Self has the layout of [T; 2], so n of them are 2n T's
+        unsafe { core::slice::from_raw_parts(slice.as_ptr().cast::<T>(), slice.len() * 2) }
+    }
+
+    /// Reinterprets a mutable slice of vectors as a mutable slice of their components, without copying.
+    pub fn cast_slice_mut(slice: &mut [Self]) -> &mut [T] {
+        // SAFETY: see cast_slice
+        unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast::<T>(), slice.len() * 2) }
+    }
+
+    /// Reinterprets a slice of components, `[x0, y0, x1, y1, ..]`, as a slice of vectors, without copying.
+    /// Returns [`None`] if the slice has an odd length.
+    /// ```
+    /// # use vecto::Vec2;
+    /// let coords = [1.0, 2.0, 3.0, 4.0];
+    /// assert_eq!(Vec2::try_cast_slice(&coords), Some(&[Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)][..]));
+    /// assert_eq!(Vec2::try_cast_slice(&coords[..3]), None);
+    /// ```
+    #[must_use]
+    pub fn try_cast_slice(slice: &[T]) -> Option<&[Self]> {
+        // SAFETY: Self has the layout (and alignment) of [T; 2]
+        slice.len().is_multiple_of(2).then(|| unsafe {
+            core::slice::from_raw_parts(slice.as_ptr().cast::<Self>(), slice.len() / 2)
+        })
+    }
+
+    /// Reinterprets a mutable slice of components as a mutable slice of vectors, without copying.
+    /// Returns [`None`] if the slice has an odd length.
+    pub fn try_cast_slice_mut(slice: &mut [T]) -> Option<&mut [Self]> {
+        // SAFETY: see try_cast_slice
+        slice.len().is_multiple_of(2).then(|| unsafe {
+            core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast::<Self>(), slice.len() / 2)
+        })
+    }
+}
+
+/// Extension trait for iterators over `(x, y)` pairs, as `FromIterator<(T, T)>` can't be implemented for [`Vec`] here.
+/// ```
+/// # use vecto::{CollectVec2s, Vec2};
+/// let csv = "1,2\n3,4";
+/// let mut points = csv
+///     .lines()
+///     .filter_map(|l| l.split_once(','))
+///     .map(|(x, y)| (x.parse::<f32>().unwrap(), y.parse::<f32>().unwrap()))
+///     .collect_vec2s();
+/// assert_eq!(points, [Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)]);
+/// points.extend([(5.0, 6.0)].into_iter().vectors());
+/// assert_eq!(points[2], Vec2::new(5.0, 6.0));
+/// ```
+pub trait CollectVec2s<T>: Iterator<Item = (T, T)> + Sized {
+    /// Converts every pair into a [`Vector2`], for [`Extend`]ing existing containers.
+    fn vectors(self) -> impl Iterator<Item = Vector2<T>> {
+        self.map(Vector2::from)
+    }
+
+    /// Collects every pair into a [`Vec`] of [`Vector2`]s.
+    fn collect_vec2s(self) -> Vec<Vector2<T>> {
+        self.vectors().collect()
+    }
+}
+
+impl<T, I: Iterator<Item = (T, T)>> CollectVec2s<T> for I {}
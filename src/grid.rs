@@ -0,0 +1,886 @@
+//! Integer grids.
+use crate::{IVec2, Vec2, Vector2};
+use core::cmp::Ordering;
+use core::ops::{Index, IndexMut};
+use std::collections::{HashSet, VecDeque};
+
+/// Which cells count as neighbours of a cell.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Connectivity {
+    /// The cells sharing an edge (von Neumann neighbourhood).
+    #[default]
+    Four,
+    /// The cells sharing an edge or a corner (Moore neighbourhood).
+    Eight,
+}
+
+impl Connectivity {
+    /// Returns the offsets to the neighbouring cells, orthogonal ones first.
+    #[must_use]
+    pub const fn offsets(self) -> &'static [IVec2] {
+        const OFFSETS: [IVec2; 8] = [
+            IVec2::new(1, 0),
+            IVec2::new(0, 1),
+            IVec2::new(-1, 0),
+            IVec2::new(0, -1),
+            IVec2::new(1, 1),
+            IVec2::new(-1, 1),
+            IVec2::new(-1, -1),
+            IVec2::new(1, -1),
+        ];
+        match self {
+            Self::Four => OFFSETS.split_at(4).0,
+            Self::Eight => &OFFSETS,
+        }
+    }
+
+    /// Returns the cells neighbouring `at`.
+    /// ```
+    /// # use vecto::{IVec2, grid::Connectivity};
+    /// let n: Vec<_> = Connectivity::Four.neighbors(IVec2::new(5, 5)).collect();
+    /// assert_eq!(n, [IVec2::new(6, 5), IVec2::new(5, 6), IVec2::new(4, 5), IVec2::new(5, 4)]);
+    /// assert_eq!(Connectivity::Eight.neighbors(IVec2::new(5, 5)).count(), 8);
+    /// ```
+    #[must_use]
+    pub fn neighbors(self, at: IVec2) -> impl ExactSizeIterator<Item = IVec2> + Clone {
+        self.offsets().iter().map(move |&o| at + o)
+    }
+}
+
+/// A `width` × `height` grid of cells, stored row by row.
+/// ```
+/// # use vecto::{IVec2, Vector2, grid::Grid};
+/// let mut g = Grid::from_fn(3, 2, |p| p.x + p.y * 10);
+/// assert_eq!(g[Vector2::new(2, 1)], 12);
+/// assert_eq!(g.get(IVec2::new(2, 1)), Some(&12));
+/// assert_eq!(g.get(IVec2::new(3, 1)), None);
+/// assert_eq!(g.get(IVec2::new(-1, 0)), None);
+/// g[Vector2::new(0, 0)] = 7;
+/// assert_eq!(g.row(0), [7, 1, 2]);
+/// assert_eq!(g.column(1).collect::<Vec<_>>(), [&1, &11]);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Construct a new [`Grid`], calling `f` with the position of each cell.
+    ///
+    /// # Panics
+    ///
+    /// If a dimension does not fit in an [`i32`].
+    #[must_use]
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(IVec2) -> T) -> Self {
+        let (Ok(w), Ok(h)) = (i32::try_from(width), i32::try_from(height)) else {
+            panic!("grid too large")
+        };
+        let cells = (0..h)
+            .flat_map(|y| (0..w).map(move |x| IVec2::new(x, y)))
+            .map(&mut f)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Construct a new [`Grid`] from cells in row-major order.
+    /// Returns [`None`] if there are not `width * height` cells.
+    #[must_use]
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        (width.checked_mul(height)? == cells.len()).then_some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Returns the number of columns.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the dimensions, `(width, height)`.
+    #[must_use]
+    pub fn size(&self) -> Vector2<usize> {
+        Vector2::new(self.width, self.height)
+    }
+
+    /// Returns true if `at` is within the grid.
+    #[must_use]
+    pub fn contains(&self, at: IVec2) -> bool {
+        self.index_of(at).is_some()
+    }
+
+    fn index_of(&self, at: IVec2) -> Option<usize> {
+        at.to_index(self.width).filter(|&i| i < self.cells.len())
+    }
+
+    /// Returns the cell at `at`, or [`None`] if it is outside the grid.
+    #[must_use]
+    pub fn get(&self, at: IVec2) -> Option<&T> {
+        self.index_of(at).map(|i| &self.cells[i])
+    }
+
+    /// Returns the cell at `at` mutably, or [`None`] if it is outside the grid.
+    pub fn get_mut(&mut self, at: IVec2) -> Option<&mut T> {
+        self.index_of(at).map(|i| &mut self.cells[i])
+    }
+
+    /// Returns the cells, row by row.
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Returns the cells mutably, row by row.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Returns row `y`.
+    ///
+    /// # Panics
+    ///
+    /// If `y` is out of bounds.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns row `y` mutably.
+    ///
+    /// # Panics
+    ///
+    /// If `y` is out of bounds.
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns an iterator over the rows.
+    #[must_use]
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[T]> + DoubleEndedIterator {
+        // chunks_exact would panic on a zero width
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Returns an iterator over column `x`, top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// If `x` is out of bounds.
+    #[must_use]
+    pub fn column(&self, x: usize) -> impl ExactSizeIterator<Item = &T> + DoubleEndedIterator {
+        assert!(x < self.width, "column out of bounds");
+        (0..self.height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    /// Returns an iterator over the columns, each going top to bottom.
+    #[must_use]
+    pub fn columns(
+        &self,
+    ) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = &T> + DoubleEndedIterator>
+           + DoubleEndedIterator {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Returns an iterator over every cell and its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        let width = self.width;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, c)| (IVec2::new((i % width) as i32, (i / width) as i32), c))
+    }
+
+    /// Returns a new grid with `f` applied to every cell.
+    /// ```
+    /// # use vecto::grid::Grid;
+    /// let g = Grid::from_fn(2, 2, |p| p.x).map(|x| x == 1);
+    /// assert_eq!(g.as_slice(), [false, true, false, true]);
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.into_iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Construct a new [`Grid`] with every cell set to `value`.
+    ///
+    /// # Panics
+    ///
+    /// If a dimension does not fit in an [`i32`].
+    #[must_use]
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        let (Ok(_), Ok(_), Some(len)) = (
+            i32::try_from(width),
+            i32::try_from(height),
+            width.checked_mul(height),
+        ) else {
+            panic!("grid too large")
+        };
+        Self {
+            width,
+            height,
+            cells: vec![value; len],
+        }
+    }
+
+    /// Sets every cell to `value`.
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+}
+
+impl<T> Index<Vector2<usize>> for Grid<T> {
+    type Output = T;
+
+    /// Returns the cell at `at`.
+    ///
+    /// # Panics
+    ///
+    /// If `at` is out of bounds.
+    fn index(&self, at: Vector2<usize>) -> &T {
+        assert!(
+            at.x < self.width && at.y < self.height,
+            "{at:?} out of bounds"
+        );
+        &self.cells[at.y * self.width + at.x]
+    }
+}
+
+impl<T> IndexMut<Vector2<usize>> for Grid<T> {
+    /// Returns the cell at `at` mutably.
+    ///
+    /// # Panics
+    ///
+    /// If `at` is out of bounds.
+    fn index_mut(&mut self, at: Vector2<usize>) -> &mut T {
+        assert!(
+            at.x < self.width && at.y < self.height,
+            "{at:?} out of bounds"
+        );
+        &mut self.cells[at.y * self.width + at.x]
+    }
+}
+
+impl IVec2 {
+    /// Returns the [taxicab distance](https://en.wikipedia.org/wiki/Taxicab_geometry) to `other`, the number of steps between them with [`Connectivity::Four`].
+    /// ```
+    /// # use vecto::IVec2;
+    /// assert_eq!(IVec2::new(1, 1).manhattan_distance(IVec2::new(4, -1)), 5);
+    /// ```
+    #[must_use]
+    pub fn manhattan_distance(self, other: Self) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Returns the [chessboard distance](https://en.wikipedia.org/wiki/Chebyshev_distance) to `other`, the number of steps between them with [`Connectivity::Eight`].
+    /// ```
+    /// # use vecto::IVec2;
+    /// assert_eq!(IVec2::new(1, 1).chebyshev_distance(IVec2::new(4, -1)), 3);
+    /// ```
+    #[must_use]
+    pub fn chebyshev_distance(self, other: Self) -> u32 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+/// Breadth-first flood fill, created by [`flood_fill`].
+#[derive(Clone, Debug)]
+pub struct FloodFill<F> {
+    passable: F,
+    connectivity: Connectivity,
+    queue: VecDeque<IVec2>,
+    seen: HashSet<IVec2>,
+}
+
+/// Returns an iterator over every cell reachable from `start` through cells for which `passable` returns true, nearest first.
+///
+/// Yields nothing if `start` itself is not passable.
+/// `passable` must only allow a finite area, or the fill will never end.
+/// ```
+/// # use vecto::{IVec2, grid::{Connectivity, flood_fill}};
+/// // a 3x3 room, with a diagonal opening to the cell at (3, 3)
+/// let passable = |p: IVec2| (0..3).contains(&p.x) && (0..3).contains(&p.y) || p == IVec2::new(3, 3);
+/// assert_eq!(flood_fill(IVec2::new(1, 1), Connectivity::Four, passable).count(), 9);
+/// assert_eq!(flood_fill(IVec2::new(1, 1), Connectivity::Eight, passable).count(), 10);
+/// assert_eq!(flood_fill(IVec2::new(5, 5), Connectivity::Four, passable).count(), 0);
+/// ```
+#[must_use]
+pub fn flood_fill<F: FnMut(IVec2) -> bool>(
+    start: IVec2,
+    connectivity: Connectivity,
+    mut passable: F,
+) -> FloodFill<F> {
+    let start = passable(start).then_some(start);
+    FloodFill {
+        passable,
+        connectivity,
+        queue: start.into_iter().collect(),
+        seen: start.into_iter().collect(),
+    }
+}
+
+impl<F: FnMut(IVec2) -> bool> Iterator for FloodFill<F> {
+    type Item = IVec2;
+
+    fn next(&mut self) -> Option<IVec2> {
+        let at = self.queue.pop_front()?;
+        for next in self.connectivity.neighbors(at) {
+            if !self.seen.contains(&next) && (self.passable)(next) {
+                self.seen.insert(next);
+                self.queue.push_back(next);
+            }
+        }
+        Some(at)
+    }
+}
+
+/// Supercover line traversal, created by [`supercover`].
+#[derive(Clone, Debug)]
+pub struct Supercover {
+    at: IVec2,
+    step: IVec2,
+    /// `(|dx|, |dy|)`.
+    len: Vector2<i64>,
+    /// Steps taken along each axis.
+    taken: Vector2<i64>,
+    /// Cells to yield before stepping again; the second one is `at`.
+    pending: [Option<IVec2>; 2],
+    started: bool,
+}
+
+/// Returns an iterator over every cell touched by the segment between the centers of cells `from` and `to`, both included.
+///
+/// Unlike [Bresenham's line](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm), this includes every cell the segment passes through,
+/// and both cells beside a corner it passes exactly through, so nothing the segment touches is missed.
+/// ```
+/// # use vecto::{IVec2, grid::supercover};
+/// let cells: Vec<_> = supercover(IVec2::new(0, 0), IVec2::new(2, 1)).collect();
+/// assert_eq!(cells, [IVec2::new(0, 0), IVec2::new(1, 0), IVec2::new(1, 1), IVec2::new(2, 1)]);
+/// // through a corner
+/// let cells: Vec<_> = supercover(IVec2::new(0, 0), IVec2::new(1, -1)).collect();
+/// assert_eq!(cells, [IVec2::new(0, 0), IVec2::new(1, 0), IVec2::new(0, -1), IVec2::new(1, -1)]);
+/// ```
+#[must_use]
+pub fn supercover(from: IVec2, to: IVec2) -> Supercover {
+    let d = to - from;
+    Supercover {
+        at: from,
+        step: IVec2::new(d.x.signum(), d.y.signum()),
+        len: Vector2::new(i64::from(d.x).abs(), i64::from(d.y).abs()),
+        taken: Vector2::new(0, 0),
+        pending: [None; 2],
+        started: false,
+    }
+}
+
+impl Iterator for Supercover {
+    type Item = IVec2;
+
+    fn next(&mut self) -> Option<IVec2> {
+        if !self.started {
+            self.started = true;
+            return Some(self.at);
+        }
+        if let Some(p) = self.pending[0].take() {
+            return Some(p);
+        }
+        if let Some(p) = self.pending[1].take() {
+            return Some(p);
+        }
+        if self.taken.x >= self.len.x && self.taken.y >= self.len.y {
+            return None;
+        }
+        // which cell border the line crosses next, compared without division
+        let decision = (1 + 2 * self.taken.x) * self.len.y - (1 + 2 * self.taken.y) * self.len.x;
+        let before = self.at;
+        match decision.cmp(&0) {
+            Ordering::Equal => {
+                // exactly through a corner, touching the cells on both sides of it
+                self.at += self.step;
+                self.taken += Vector2::new(1, 1);
+                self.pending = [Some(IVec2::new(before.x, self.at.y)), Some(self.at)];
+                return Some(IVec2::new(self.at.x, before.y));
+            }
+            Ordering::Less => {
+                self.at.x += self.step.x;
+                self.taken.x += 1;
+            }
+            Ordering::Greater => {
+                self.at.y += self.step.y;
+                self.taken.y += 1;
+            }
+        }
+        Some(self.at)
+    }
+}
+
+/// Computes the cells visible from `origin` within `radius`, with [recursive shadowcasting](https://www.roguebasin.com/index.php/FOV_using_recursive_shadowcasting).
+///
+/// Cells for which `opaque` returns true block sight, but are visible themselves.
+/// ```
+/// # use vecto::{IVec2, grid::field_of_view};
+/// let pillar = IVec2::new(2, 0);
+/// let seen = field_of_view(IVec2::new(0, 0), 4, |p| p == pillar);
+/// assert!(seen.contains(&IVec2::new(0, 0)));
+/// assert!(seen.contains(&pillar));
+/// assert!(!seen.contains(&IVec2::new(3, 0))); // behind the pillar
+/// assert!(seen.contains(&IVec2::new(0, -4)));
+/// assert!(!seen.contains(&IVec2::new(3, 3))); // out of range
+/// ```
+#[must_use]
+pub fn field_of_view(
+    origin: IVec2,
+    radius: u16,
+    mut opaque: impl FnMut(IVec2) -> bool,
+) -> HashSet<IVec2> {
+    /// Maps the octant's (column, row) onto x and y.
+    const OCTANTS: [[i32; 4]; 8] = [
+        [1, 0, 0, 1],
+        [0, 1, 1, 0],
+        [0, -1, 1, 0],
+        [-1, 0, 0, 1],
+        [-1, 0, 0, -1],
+        [0, -1, -1, 0],
+        [0, 1, -1, 0],
+        [1, 0, 0, -1],
+    ];
+    struct Caster<'a, F> {
+        origin: IVec2,
+        radius: i32,
+        opaque: &'a mut F,
+        visible: HashSet<IVec2>,
+    }
+    impl<F: FnMut(IVec2) -> bool> Caster<'_, F> {
+        /// Scans rows from `row` outwards, between the slopes `start` (the steeper one) and `end`.
+        #[allow(clippy::cast_precision_loss)]
+        fn cast(&mut self, row: i32, mut start: f32, end: f32, [xx, xy, yx, yy]: [i32; 4]) {
+            if start < end {
+                return;
+            }
+            let mut next_start = start;
+            for distance in row..=self.radius {
+                let mut blocked = false;
+                let dy = -distance;
+                for dx in -distance..=0 {
+                    // slopes to the corners of this cell
+                    let left = (dx as f32 - 0.5) / (dy as f32 + 0.5);
+                    let right = (dx as f32 + 0.5) / (dy as f32 - 0.5);
+                    if start < right {
+                        continue;
+                    }
+                    if end > left {
+                        break;
+                    }
+                    let cell = self.origin + IVec2::new(dx * xx + dy * xy, dx * yx + dy * yy);
+                    if dx * dx + dy * dy <= self.radius * self.radius {
+                        self.visible.insert(cell);
+                    }
+                    let opaque = (self.opaque)(cell);
+                    if blocked {
+                        if opaque {
+                            next_start = right;
+                        } else {
+                            blocked = false;
+                            start = next_start;
+                        }
+                    } else if opaque && distance < self.radius {
+                        blocked = true;
+                        self.cast(distance + 1, start, left, [xx, xy, yx, yy]);
+                        next_start = right;
+                    }
+                }
+                if blocked {
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut caster = Caster {
+        origin,
+        radius: i32::from(radius),
+        opaque: &mut opaque,
+        visible: HashSet::from([origin]),
+    };
+    for octant in OCTANTS {
+        caster.cast(1, 1.0, 0.0, octant);
+    }
+    caster.visible
+}
+
+/// The nearest seed to a cell, from [`Grid::distance_transform`] or [`jump_flood`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Nearest<S = IVec2> {
+    /// The seed.
+    pub seed: S,
+    /// The straight line distance to the seed, in cells.
+    pub distance: f32,
+}
+
+impl Grid<bool> {
+    /// Returns the exact [Euclidean distance transform](https://en.wikipedia.org/wiki/Distance_transform) of the grid:
+    /// for every cell, the nearest `true` cell (seed) and the distance to it. Every cell is [`None`] if there are no seeds.
+    ///
+    /// Uses the linear time algorithm of Felzenszwalb and Huttenlocher. When seeds are equally near, which one is picked is unspecified.
+    /// ```
+    /// # use vecto::{IVec2, grid::Grid};
+    /// let seeds = Grid::from_fn(5, 5, |p| p == IVec2::new(0, 0) || p == IVec2::new(4, 4));
+    /// let field = seeds.distance_transform();
+    /// let far = field.get(IVec2::new(3, 0)).unwrap().unwrap();
+    /// assert_eq!((far.seed, far.distance), (IVec2::new(0, 0), 3.0));
+    /// let far = field.get(IVec2::new(4, 1)).unwrap().unwrap();
+    /// assert_eq!((far.seed, far.distance), (IVec2::new(4, 4), 3.0));
+    /// assert_eq!(field.get(IVec2::new(4, 4)).unwrap().unwrap().distance, 0.0);
+    /// ```
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_possible_wrap,
+        clippy::cast_precision_loss
+    )]
+    pub fn distance_transform(&self) -> Grid<Option<Nearest>> {
+        let (w, h) = (self.width, self.height);
+        // the nearest seed's row within each column
+        let mut rows = Grid::filled(w, h, None::<usize>);
+        for x in 0..w {
+            let mut last = None;
+            for y in 0..h {
+                if self.cells[y * w + x] {
+                    last = Some(y);
+                }
+                rows.cells[y * w + x] = last;
+            }
+            let mut next = None;
+            for y in (0..h).rev() {
+                if self.cells[y * w + x] {
+                    next = Some(y);
+                }
+                let cell = &mut rows.cells[y * w + x];
+                if let Some(n) = next {
+                    if cell.is_none_or(|above| n - y < y - above) {
+                        *cell = Some(n);
+                    }
+                }
+            }
+        }
+        // then along each row, the lower envelope of the parabolas (x - column)² + (vertical distance)²
+        let mut out = Grid::filled(w, h, None);
+        let mut columns: Vec<(usize, f64)> = Vec::with_capacity(w);
+        let mut bounds: Vec<f64> = Vec::with_capacity(w);
+        for y in 0..h {
+            columns.clear();
+            bounds.clear();
+            for x in 0..w {
+                let Some(row) = rows.cells[y * w + x] else {
+                    continue;
+                };
+                let height = (row.abs_diff(y) as f64).powi(2);
+                let q = x as f64;
+                while let Some(&(p, p_height)) = columns.last() {
+                    let p = p as f64;
+                    let crossing = ((height + q * q) - (p_height + p * p)) / (2.0 * (q - p));
+                    if bounds.last().is_some_and(|&b| crossing <= b) {
+                        columns.pop();
+                        bounds.pop();
+                    } else {
+                        bounds.push(crossing);
+                        break;
+                    }
+                }
+                columns.push((x, height));
+            }
+            // bounds[i] separates columns[i] and columns[i + 1]
+            if columns.is_empty() {
+                // no seeds at all
+                return out;
+            }
+            let mut k = 0;
+            for x in 0..w {
+                while bounds.get(k).is_some_and(|&b| b < x as f64) {
+                    k += 1;
+                }
+                let column = columns[k].0;
+                let row = rows.cells[y * w + column].unwrap_or_default();
+                let dx = column.abs_diff(x) as f32;
+                let dy = row.abs_diff(y) as f32;
+                out.cells[y * w + x] = Some(Nearest {
+                    seed: IVec2::new(column as i32, row as i32),
+                    distance: dx.hypot(dy),
+                });
+            }
+        }
+        out
+    }
+}
+
+/// Approximates the nearest of `seeds` to every cell of a `width` by `height` grid with the [jump flooding algorithm](https://en.wikipedia.org/wiki/Jump_flooding_algorithm).
+///
+/// Cells are `cell_size` wide, so cell `(x, y)` is centered at `(x + 0.5, y + 0.5) * cell_size`, in the same space as the seeds.
+/// A smaller `cell_size` gives a finer field for more work. [`Nearest::seed`] is an index into `seeds`, and the distance is from the cell center.
+/// Every cell is [`None`] if no seed lies inside the grid.
+///
+/// Runs in `O(cells · log(size))`, and is nearly always exact (it does an extra pass of step 1 to fix most errors).
+/// Use [`Grid::distance_transform`] when seeds are cells and it must be exact.
+/// ```
+/// # use vecto::{Vec2, Vector2, grid::jump_flood};
+/// let seeds = [Vec2::new(1.0, 1.0), Vec2::new(7.0, 3.0)];
+/// let voronoi = jump_flood(8, 4, 1.0, &seeds);
+/// assert_eq!(voronoi[Vector2::new(0usize, 0)].unwrap().seed, 0);
+/// let right = voronoi[Vector2::new(7usize, 3)].unwrap();
+/// assert_eq!(right.seed, 1);
+/// assert!((right.distance - 0.5f32.hypot(0.5)).abs() < 1e-6);
+/// ```
+///
+/// # Panics
+///
+/// If `cell_size` is not positive.
+#[must_use]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+pub fn jump_flood(
+    width: usize,
+    height: usize,
+    cell_size: f32,
+    seeds: &[Vec2],
+) -> Grid<Option<Nearest<usize>>> {
+    assert!(cell_size > 0.0, "cell size must be positive");
+    let center = |x: usize, y: usize| Vec2::new(x as f32 + 0.5, y as f32 + 0.5) * cell_size;
+    let mut field = Grid::filled(width, height, None::<usize>);
+    for (i, &seed) in seeds.iter().enumerate() {
+        let cell = seed / cell_size;
+        if cell.x < 0.0 || cell.y < 0.0 {
+            continue;
+        }
+        let (x, y) = (cell.x as usize, cell.y as usize);
+        if x >= width || y >= height {
+            continue;
+        }
+        let here = center(x, y);
+        let slot = &mut field.cells[y * width + x];
+        if slot.is_none_or(|other| {
+            seed.distance_squared_to(&here) < seeds[other].distance_squared_to(&here)
+        }) {
+            *slot = Some(i);
+        }
+    }
+    let mut next = field.clone();
+    let mut step = width.max(height).next_power_of_two() / 2;
+    let mut extra = true;
+    while step > 0 {
+        for y in 0..height {
+            for x in 0..width {
+                let here = center(x, y);
+                let mut best = field.cells[y * width + x];
+                for dy in [-1, 0, 1] {
+                    for dx in [-1, 0, 1] {
+                        let (nx, ny) = (
+                            x as isize + dx * step as isize,
+                            y as isize + dy * step as isize,
+                        );
+                        if nx < 0 || ny < 0 || nx as usize >= width || ny as usize >= height {
+                            continue;
+                        }
+                        let Some(candidate) = field.cells[ny as usize * width + nx as usize] else {
+                            continue;
+                        };
+                        if best.is_none_or(|b| {
+                            seeds[candidate].distance_squared_to(&here)
+                                < seeds[b].distance_squared_to(&here)
+                        }) {
+                            best = Some(candidate);
+                        }
+                    }
+                }
+                next.cells[y * width + x] = best;
+            }
+        }
+        core::mem::swap(&mut field, &mut next);
+        if step == 1 && extra {
+            extra = false;
+        } else {
+            step /= 2;
+        }
+    }
+    Grid::from_fn(width, height, |p| {
+        let (x, y) = (p.x as usize, p.y as usize);
+        field.cells[y * width + x].map(|seed| Nearest {
+            seed,
+            distance: seeds[seed].distance_to(&center(x, y)),
+        })
+    })
+}
+
+#[cfg(feature = "pathfinding")]
+mod path {
+    use super::Connectivity;
+    use crate::IVec2;
+    use core::cmp::Ordering;
+    use std::collections::{BinaryHeap, HashMap};
+
+    /// An open cell, ordered so that the [`BinaryHeap`] pops the lowest estimate first.
+    struct Open {
+        estimate: f32,
+        cost: f32,
+        at: IVec2,
+    }
+
+    impl PartialEq for Open {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+
+    impl Eq for Open {}
+
+    impl PartialOrd for Open {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Open {
+        fn cmp(&self, other: &Self) -> Ordering {
+            other.estimate.total_cmp(&self.estimate)
+        }
+    }
+
+    /// Finds the cheapest path from `start` to `goal` with [A*](https://en.wikipedia.org/wiki/A*_search_algorithm).
+    ///
+    /// Steps to cells for which `passable` returns false are not taken,
+    /// and diagonal steps may not cut the corner of an impassable cell.
+    /// `cost(from, to)` gives the cost of a step; to find the cheapest path it must be at least the step's length (1 orthogonally, √2 diagonally).
+    /// If it can be less, use [`dijkstra`].
+    ///
+    /// Returns the path, including `start` and `goal`, or [`None`] if `goal` is unreachable.
+    /// `passable` must only allow a finite area, or an unreachable `goal` will search forever.
+    /// ```
+    /// # use vecto::{IVec2, grid::{Connectivity, astar}};
+    /// // a wall at x = 2, with a gap at y = 4
+    /// let passable = |p: IVec2| (0..5).contains(&p.x) && (0..5).contains(&p.y) && (p.x != 2 || p.y == 4);
+    /// let path = astar(IVec2::new(0, 0), IVec2::new(4, 0), Connectivity::Four, passable, |_, _| 1.0).unwrap();
+    /// assert_eq!(path.len(), 13);
+    /// assert!(path.contains(&IVec2::new(2, 4)));
+    /// assert_eq!(astar(IVec2::new(0, 0), IVec2::new(9, 0), Connectivity::Four, passable, |_, _| 1.0), None);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn astar(
+        start: IVec2,
+        goal: IVec2,
+        connectivity: Connectivity,
+        passable: impl FnMut(IVec2) -> bool,
+        cost: impl FnMut(IVec2, IVec2) -> f32,
+    ) -> Option<Vec<IVec2>> {
+        let heuristic = move |at: IVec2| {
+            let d = at - goal;
+            let (dx, dy) = (d.x.unsigned_abs() as f32, d.y.unsigned_abs() as f32);
+            match connectivity {
+                Connectivity::Four => dx + dy,
+                // octile distance
+                Connectivity::Eight => dx.max(dy) + (std::f32::consts::SQRT_2 - 1.0) * dx.min(dy),
+            }
+        };
+        search(start, goal, connectivity, passable, cost, heuristic)
+    }
+
+    /// Finds the cheapest path from `start` to `goal` with [Dijkstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm).
+    ///
+    /// Like [`astar`], but allows `cost` to be any non negative value, at the price of searching more cells.
+    /// ```
+    /// # use vecto::{IVec2, grid::{Connectivity, dijkstra}};
+    /// let passable = |p: IVec2| (0..5).contains(&p.x) && (0..5).contains(&p.y);
+    /// // moving along y = 4 is nearly free
+    /// let cost = |_, to: IVec2| if to.y == 4 { 0.01 } else { 1.0 };
+    /// let path = dijkstra(IVec2::new(0, 3), IVec2::new(4, 3), Connectivity::Four, passable, cost).unwrap();
+    /// assert_eq!(path.len(), 7);
+    /// ```
+    #[must_use]
+    pub fn dijkstra(
+        start: IVec2,
+        goal: IVec2,
+        connectivity: Connectivity,
+        passable: impl FnMut(IVec2) -> bool,
+        cost: impl FnMut(IVec2, IVec2) -> f32,
+    ) -> Option<Vec<IVec2>> {
+        search(start, goal, connectivity, passable, cost, |_| 0.0)
+    }
+
+    fn search(
+        start: IVec2,
+        goal: IVec2,
+        connectivity: Connectivity,
+        mut passable: impl FnMut(IVec2) -> bool,
+        mut cost: impl FnMut(IVec2, IVec2) -> f32,
+        heuristic: impl Fn(IVec2) -> f32,
+    ) -> Option<Vec<IVec2>> {
+        let mut came_from = HashMap::new();
+        let mut best = HashMap::from([(start, 0.0)]);
+        let mut open = BinaryHeap::from([Open {
+            estimate: heuristic(start),
+            cost: 0.0,
+            at: start,
+        }]);
+        while let Some(Open {
+            cost: so_far, at, ..
+        }) = open.pop()
+        {
+            if at == goal {
+                let mut path = vec![at];
+                while let Some(&from) = came_from.get(path.last()?) {
+                    path.push(from);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if best.get(&at).is_some_and(|&b| so_far > b) {
+                // already reached more cheaply
+                continue;
+            }
+            for next in connectivity.neighbors(at) {
+                let diagonal = next.x != at.x && next.y != at.y;
+                let cuts_corner = diagonal
+                    && !(passable(IVec2::new(next.x, at.y)) && passable(IVec2::new(at.x, next.y)));
+                if cuts_corner || !passable(next) {
+                    continue;
+                }
+                let through = so_far + cost(at, next);
+                if best.get(&next).is_none_or(|&b| through < b) {
+                    best.insert(next, through);
+                    came_from.insert(next, at);
+                    open.push(Open {
+                        estimate: through + heuristic(next),
+                        cost: through,
+                        at: next,
+                    });
+                }
+            }
+        }
+        None
+    }
+}
+#[cfg(feature = "pathfinding")]
+pub use path::{astar, dijkstra};
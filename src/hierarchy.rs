@@ -0,0 +1,161 @@
+use crate::Transform2D;
+
+/// A node in a [`TransformTree`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TransformNode {
+    parent: Option<usize>,
+    local: Transform2D,
+    global: Transform2D,
+    dirty: bool,
+}
+
+impl TransformNode {
+    /// Returns the index of the parent, or [`None`] for a root.
+    #[must_use]
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+
+    /// Returns the transform relative to the parent.
+    #[must_use]
+    pub fn local(&self) -> Transform2D {
+        self.local
+    }
+
+    /// Returns the transform relative to the world, as of the last [`TransformTree::update`].
+    #[must_use]
+    pub fn global(&self) -> Transform2D {
+        self.global
+    }
+}
+
+/// A hierarchy of transforms, where each node is placed relative to its parent, like the bones of a sprite rig or nested UI.
+///
+/// Nodes are referred to by index. A parent is always added before its children, so one pass in order
+/// recomputes the global transforms, and only the changed nodes and their descendants are recomputed.
+/// ```
+/// # use vecto::{Transform2D, TransformTree, Vec2};
+/// let moved = |x, y| Transform2D::new(Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0), Vec2::new(x, y));
+/// let mut rig = TransformTree::new();
+/// let body = rig.add(None, moved(10.0, 0.0));
+/// let arm = rig.add(Some(body), moved(0.0, 2.0));
+/// let hand = rig.add(Some(arm), moved(1.0, 0.0));
+/// rig.update();
+/// assert_eq!(rig.global(hand).origin, Vec2::new(11.0, 2.0));
+/// rig.set_local(body, moved(20.0, 0.0));
+/// rig.update();
+/// assert_eq!(rig.global(hand).origin, Vec2::new(21.0, 2.0));
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TransformTree {
+    nodes: Vec<TransformNode>,
+}
+
+impl TransformTree {
+    /// Construct a new, empty [`TransformTree`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+
+    /// Returns the number of nodes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if there are no nodes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Adds a node placed at `local` relative to `parent` (or the world, for [`None`]), and returns its index.
+    ///
+    /// # Panics
+    ///
+    /// If `parent` is not a node.
+    pub fn add(&mut self, parent: Option<usize>, local: Transform2D) -> usize {
+        if let Some(parent) = parent {
+            assert!(parent < self.nodes.len(), "parent {parent} does not exist");
+        }
+        self.nodes.push(TransformNode {
+            parent,
+            local,
+            global: local,
+            dirty: true,
+        });
+        self.nodes.len() - 1
+    }
+
+    /// Returns node `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    #[must_use]
+    pub fn node(&self, index: usize) -> &TransformNode {
+        &self.nodes[index]
+    }
+
+    /// Returns an iterator over the nodes, parents before children.
+    #[must_use]
+    pub fn nodes(&self) -> impl ExactSizeIterator<Item = &TransformNode> {
+        self.nodes.iter()
+    }
+
+    /// Returns the transform of node `index` relative to its parent.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    #[must_use]
+    pub fn local(&self, index: usize) -> Transform2D {
+        self.nodes[index].local
+    }
+
+    /// Moves node `index` relative to its parent, marking it to be recomputed by the next [`TransformTree::update`].
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn set_local(&mut self, index: usize, local: Transform2D) {
+        let node = &mut self.nodes[index];
+        node.local = local;
+        node.dirty = true;
+    }
+
+    /// Returns the transform of node `index` relative to the world, as of the last [`TransformTree::update`].
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    #[must_use]
+    pub fn global(&self, index: usize) -> Transform2D {
+        self.nodes[index].global
+    }
+
+    /// Returns true if a node has been changed since the last [`TransformTree::update`].
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        self.nodes.iter().any(|n| n.dirty)
+    }
+
+    /// Recomputes the global transforms of the changed nodes and their descendants.
+    pub fn update(&mut self) {
+        for i in 0..self.nodes.len() {
+            let node = self.nodes[i];
+            // a parent recomputed earlier in this pass is still marked
+            let parent = node.parent.map(|p| self.nodes[p]);
+            if !node.dirty && !parent.is_some_and(|p| p.dirty) {
+                continue;
+            }
+            let node = &mut self.nodes[i];
+            node.global = parent.map_or(node.local, |p| p.global * node.local);
+            node.dirty = true;
+        }
+        for node in &mut self.nodes {
+            node.dirty = false;
+        }
+    }
+}
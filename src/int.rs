@@ -0,0 +1,150 @@
+use crate::{IVec2, Vector2};
+
+macro_rules! reduced {
+    ($($t:ty)+; $abs:ident) => {$(
+        impl Vector2<$t> {
+            /// Returns the vector divided by the greatest common divisor of its components, keeping their signs:
+            /// the shortest vector pointing the same way with integer components. The zero vector is returned unchanged.
+            ///
+            /// Rays from the same point with equal reduced directions overlap, which makes this useful for
+            /// deduplicating lines of sight and comparing slopes without floats.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::new(6, 4).reduced(), Vector2::new(3, 2));")]
+            #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::new(-4, 6).reduced(), Vector2::new(-2, 3));")]
+            #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::new(0, 5).reduced(), Vector2::new(0, 1));")]
+            #[doc = concat!("assert_eq!(Vector2::new(", stringify!($t), "::MIN, 0).reduced(), Vector2::new(-1, 0));")]
+            #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::new(0, 0).reduced(), Vector2::new(0, 0));")]
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn reduced(self) -> Self {
+                let (mut a, mut b) = (self.x.$abs(), self.y.$abs());
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                if a <= 1 {
+                    return self;
+                }
+                // only `MIN` has a magnitude too large to fit, in which case the components are `MIN` or zero
+                match <$t>::try_from(a) {
+                    Ok(gcd) => Self::new(self.x / gcd, self.y / gcd),
+                    Err(_) => self.map(<$t>::signum),
+                }
+            }
+        }
+    )+};
+    ($($t:ty)+) => {$(
+        impl Vector2<$t> {
+            /// Returns the vector divided by the greatest common divisor of its components:
+            /// the shortest vector pointing the same way with integer components. The zero vector is returned unchanged.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::new(6, 4).reduced(), Vector2::new(3, 2));")]
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn reduced(self) -> Self {
+                let (mut a, mut b) = (self.x, self.y);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                if a <= 1 {
+                    return self;
+                }
+                Self::new(self.x / a, self.y / a)
+            }
+        }
+    )+};
+}
+reduced!(i8 i16 i32 i64 i128 isize; unsigned_abs);
+reduced!(u8 u16 u32 u64 u128 usize);
+
+macro_rules! abs_diff {
+    ($($t:ty => $u:ty),+) => {$(
+        impl Vector2<$t> {
+            /// Returns the component-wise absolute difference `|self - with|`.
+            ///
+            #[doc = concat!("Like [`", stringify!($t), "::abs_diff`], the result is unsigned, so it never overflows.")]
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("assert_eq!(Vector2::<", stringify!($t), ">::new(3, 100).abs_diff(Vector2::new(5, 0)), Vector2::new(2, 100));")]
+            #[doc = concat!("assert_eq!(Vector2::new(", stringify!($t), "::MIN, 0).abs_diff(Vector2::new(", stringify!($t), "::MAX, 0)), Vector2::new(", stringify!($u), "::MAX, 0));")]
+            /// ```
+            #[must_use]
+            #[inline]
+            pub fn abs_diff(self, with: Self) -> Vector2<$u> {
+                Vector2::new(self.x.abs_diff(with.x), self.y.abs_diff(with.y))
+            }
+        }
+    )+};
+}
+abs_diff!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+abs_diff!(u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize);
+
+impl IVec2 {
+    /// Returns the row-major index of `self` in rows of `width` cells, `y * width + x`.
+    ///
+    /// Returns [`None`] if `self` is negative, not within `0..width` or the index overflows.
+    /// ```
+    /// # use vecto::IVec2;
+    /// assert_eq!(IVec2::new(2, 3).to_index(10), Some(32));
+    /// assert_eq!(IVec2::new(10, 3).to_index(10), None);
+    /// assert_eq!(IVec2::new(-1, 3).to_index(10), None);
+    /// ```
+    #[must_use]
+    pub fn to_index(self, width: usize) -> Option<usize> {
+        let x = usize::try_from(self.x).ok().filter(|&x| x < width)?;
+        let y = usize::try_from(self.y).ok()?;
+        y.checked_mul(width)?.checked_add(x)
+    }
+
+    /// Inverse of [`IVec2::to_index`]: returns the position of `index` in rows of `width` cells.
+    ///
+    /// Returns [`None`] if `width` is zero or the position does not fit in an [`IVec2`].
+    /// ```
+    /// # use vecto::IVec2;
+    /// assert_eq!(IVec2::from_index(32, 10), Some(IVec2::new(2, 3)));
+    /// assert_eq!(IVec2::from_index(32, 0), None);
+    /// ```
+    #[must_use]
+    pub fn from_index(index: usize, width: usize) -> Option<Self> {
+        Some(Self::new(
+            i32::try_from(index.checked_rem(width)?).ok()?,
+            i32::try_from(index / width).ok()?,
+        ))
+    }
+
+    /// Returns the index of `self` in a buffer where each step along X is `stride.x` elements and each row is `stride.y` elements,
+    /// `y * stride.y + x * stride.x`. Useful for interleaved or padded buffers.
+    ///
+    /// Returns [`None`] if `self` is negative or the index overflows.
+    /// ```
+    /// # use vecto::{IVec2, Vector2};
+    /// // rgba pixels, 4 elements each, in rows padded to 64 elements
+    /// assert_eq!(IVec2::new(2, 3).to_strided_index(Vector2::new(4, 64)), Some(200));
+    /// ```
+    #[must_use]
+    pub fn to_strided_index(self, stride: Vector2<usize>) -> Option<usize> {
+        let x = usize::try_from(self.x).ok()?.checked_mul(stride.x)?;
+        let y = usize::try_from(self.y).ok()?.checked_mul(stride.y)?;
+        y.checked_add(x)
+    }
+
+    /// Inverse of [`IVec2::to_strided_index`]: returns the position of the element containing `index`.
+    ///
+    /// Returns [`None`] if a stride is zero or the position does not fit in an [`IVec2`].
+    /// ```
+    /// # use vecto::{IVec2, Vector2};
+    /// assert_eq!(IVec2::from_strided_index(200, Vector2::new(4, 64)), Some(IVec2::new(2, 3)));
+    /// assert_eq!(IVec2::from_strided_index(203, Vector2::new(4, 64)), Some(IVec2::new(2, 3)));
+    /// ```
+    #[must_use]
+    pub fn from_strided_index(index: usize, stride: Vector2<usize>) -> Option<Self> {
+        let within_row = index.checked_rem(stride.y)?;
+        Some(Self::new(
+            i32::try_from(within_row.checked_div(stride.x)?).ok()?,
+            i32::try_from(index / stride.y).ok()?,
+        ))
+    }
+}
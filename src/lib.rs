@@ -4,11 +4,88 @@
 //! let mut v = Vec2::new(5.0, 7.0);
 //! v *= 2.0;
 //! assert_eq!(v, Vec2::new(10.0, 14.0));
+//! // tuples and arrays work as offsets too
+//! assert_eq!(v + (1.0, 2.0), Vec2::new(11.0, 16.0));
+//! v -= [10.0, 4.0];
+//! assert_eq!(v, Vec2::new(0.0, 10.0));
 //! ````
+//!
+//! # Features
+//!
+//! Only the vector types and their methods (including the [`pixel`] conversions), the types exported at the root,
+//! and the [`angle`] helpers they use are enabled by default. Every other module has a feature of its own.
+//!
+//! - `batch`: the `batch` module.
+//! - `curves`: the `curve` module.
+//! - `ease`: the `ease` module.
+//! - `grid`: the `grid` module. `pathfinding` adds A* and Dijkstra to it.
+//! - `motion`: the `motion` module. With `shapes`, also following paths and circle impacts.
+//! - `noise`: the `noise` module.
+//! - `robust`: the `robust` module.
+//! - `sample`: the `sample` module.
+//! - `sdf`: the `sdf` module.
+//! - `shapes`: the `shape` module, fitting shapes to points in the `fit` module, and the `triangulation` module.
+//! - `spatial`: the `field` module.
+//! - `rand`: random sampling in the `sample` module, and RANSAC in the `fit` module.
+//! - `serde`: `Serialize` and `Deserialize`, see the `serde` module.
+//! - `defmt`, `crevice`, `encase`, `mlua`, `ordered-float`, `pyo3`, `schemars`, `speedy`, `sqlx-postgres`, `wasm` and `zerocopy`:
+//!   integrations with the crates of the same name.
 #![allow(mixed_script_confusables)]
 #![warn(clippy::pedantic, clippy::dbg_macro, missing_docs)]
+mod aligned;
+pub mod angle;
+#[cfg(feature = "batch")]
+pub mod batch;
+#[cfg(feature = "curves")]
+pub mod curve;
+#[cfg(feature = "ease")]
+pub mod ease;
+mod ext;
+#[cfg(feature = "spatial")]
+pub mod field;
+#[cfg(feature = "shapes")]
+pub mod fit;
+mod float;
+mod frame;
 mod from;
+#[cfg(feature = "grid")]
+pub mod grid;
+mod hierarchy;
+mod int;
+mod matrix;
+#[cfg(feature = "motion")]
+pub mod motion;
+#[cfg(feature = "noise")]
+pub mod noise;
 mod ops;
+pub mod pixel;
+mod polar;
+mod rect;
+#[cfg(feature = "robust")]
+pub mod robust;
+#[cfg(feature = "sample")]
+pub mod sample;
+#[cfg(feature = "sdf")]
+pub mod sdf;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "shapes")]
+pub mod shape;
+mod size;
+mod transform;
+#[cfg(feature = "shapes")]
+pub mod triangulation;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub use aligned::AlignedVec2;
+pub use frame::Frame2;
+pub use from::{CollectVec2s, TryFromSliceError};
+pub use hierarchy::{TransformNode, TransformTree};
+pub use matrix::Matrix2;
+pub use polar::Polar;
+pub use rect::{Anchor, Margins, Rect2, ScaleMode};
+pub use size::{RoundingMode, Size2};
+pub use transform::Transform2D;
 
 #[doc(hidden)]
 pub trait Kinda
@@ -48,8 +125,17 @@ use umath::generic_float::{FloatAlone, Rounding};
 /// Alias for <code>[`Vector2`]<[`f32`]></code>
 pub type Vec2 = Vector2<f32>;
 
+/// Alias for <code>[`Vector2`]<[`i32`]></code>
+pub type IVec2 = Vector2<i32>;
+
 /// Vector2.
+///
+/// Laid out like `[T; 2]`: X then Y, with the alignment of `T`. For 8 byte aligned [`Vec2`]s, see [`AlignedVec2`].
 #[derive(Copy, Clone, PartialEq, PartialOrd, Default, Hash, Eq, Ord)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::FromZeroes, zerocopy::FromBytes)
+)]
 #[repr(C)]
 pub struct Vector2<T> {
     /// The vector's X component.
@@ -66,13 +152,168 @@ impl<T: std::fmt::Debug> std::fmt::Debug for Vector2<T> {
 
 impl<T> Vector2<T> {
     /// Construct a new [`Vector2`].
+    #[inline]
     pub const fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
+
+    /// Returns a copy of the vector with X replaced by `x`.
+    /// ```
+    /// # use vecto::Vec2;
+    /// let ground_y = 10.0;
+    /// assert_eq!(Vec2::new(3.0, 4.0).with_y(ground_y), Vec2::new(3.0, 10.0));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn with_x(self, x: T) -> Self {
+        Self { x, ..self }
+    }
+
+    /// Returns a copy of the vector with Y replaced by `y`.
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn with_y(self, y: T) -> Self {
+        Self { y, ..self }
+    }
+
+    /// Sets X to `x`, returning the vector for chaining.
+    /// ```
+    /// # use vecto::Vec2;
+    /// let mut v = Vec2::ZERO;
+    /// v.set_x(1.0).set_y(2.0);
+    /// assert_eq!(v, Vec2::new(1.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn set_x(&mut self, x: T) -> &mut Self {
+        self.x = x;
+        self
+    }
+
+    /// Sets Y to `y`, returning the vector for chaining.
+    #[inline]
+    pub fn set_y(&mut self, y: T) -> &mut Self {
+        self.y = y;
+        self
+    }
+
+    /// Construct a new [`Vector2`] by calling `f` with each axis: `0` for X, then `1` for Y.
+    /// ```
+    /// # use vecto::Vec2;
+    /// assert_eq!(Vec2::from_fn(|axis| axis as f32 + 1.0), Vec2::new(1.0, 2.0));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_fn(mut f: impl FnMut(usize) -> T) -> Self {
+        let x = f(0);
+        Self::new(x, f(1))
+    }
+
+    /// Returns a new vector with `f` applied to each component.
+    /// ```
+    /// # use vecto::{IVec2, Vec2};
+    /// assert_eq!(Vec2::new(1.5, -2.5).map(|c| c as i32), IVec2::new(1, -2));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Vector2<U> {
+        Vector2::new(f(self.x), f(self.y))
+    }
+
+    /// Returns a new vector combining each component with the matching component of `with`.
+    /// ```
+    /// # use vecto::Vec2;
+    /// // clamp each axis to its own limit
+    /// let limits = Vec2::new(1.0, 5.0);
+    /// assert_eq!(Vec2::new(3.0, 3.0).zip(limits, f32::min), Vec2::new(1.0, 3.0));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn zip<U, R>(self, with: Vector2<U>, mut f: impl FnMut(T, U) -> R) -> Vector2<R> {
+        Vector2::new(f(self.x, with.x), f(self.y, with.y))
+    }
+
+    /// Folds the components into one value, X first.
+    /// ```
+    /// # use vecto::Vec2;
+    /// assert_eq!(Vec2::new(3.0, 4.0).fold(0.0, |acc, c| acc + c * c), 25.0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn fold<A>(self, init: A, mut f: impl FnMut(A, T) -> A) -> A {
+        let acc = f(init, self.x);
+        f(acc, self.y)
+    }
+
+    /// Returns the smaller component. Returns Y if they are equal or unordered.
+    /// ```
+    /// # use vecto::Vec2;
+    /// assert_eq!(Vec2::new(3.0, -4.0).min_element(), -4.0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn min_element(self) -> T
+    where
+        T: PartialOrd,
+    {
+        if self.x < self.y {
+            self.x
+        } else {
+            self.y
+        }
+    }
+
+    /// Returns the larger component. Returns Y if they are equal or unordered.
+    /// ```
+    /// # use vecto::Vec2;
+    /// // the side of a square containing a 3x4 rectangle
+    /// assert_eq!(Vec2::new(3.0, 4.0).max_element(), 4.0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn max_element(self) -> T
+    where
+        T: PartialOrd,
+    {
+        if self.x > self.y {
+            self.x
+        } else {
+            self.y
+        }
+    }
+
+    /// Returns `x + y`.
+    /// ```
+    /// # use vecto::IVec2;
+    /// assert_eq!(IVec2::new(3, 4).element_sum(), 7);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn element_sum(self) -> T
+    where
+        T: std::ops::Add<T, Output = T>,
+    {
+        self.x + self.y
+    }
+
+    /// Returns `x * y`.
+    /// ```
+    /// # use vecto::Vec2;
+    /// // the area of a 3x4 rectangle
+    /// assert_eq!(Vec2::new(3.0, 4.0).element_product(), 12.0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn element_product(self) -> T
+    where
+        T: std::ops::Mul<T, Output = T>,
+    {
+        self.x * self.y
+    }
 }
 
 impl<T: Copy> Vector2<T> {
     /// Construct a new [`Vector2`] with x and y set to the given value.
+    #[inline]
     pub const fn splat(x: T) -> Self {
         Self { x, y: x }
     }
@@ -89,6 +330,148 @@ impl Vec2 {
     pub const UP: Vec2 = Vec2::new(0.0, -1.0);
     /// Down unit vector. Y-Down, so points +Y. `(0, 1)`
     pub const DOWN: Vec2 = Vec2::new(0.0, 1.0);
+
+    /// Returns `n` evenly spaced points from `a` to `b`, both included.
+    /// ```
+    /// # use vecto::Vec2;
+    /// let points: Vec<_> = Vec2::linspace(Vec2::ZERO, Vec2::new(3.0, 6.0), 4).collect();
+    /// assert_eq!(points, [Vec2::ZERO, Vec2::new(1.0, 2.0), Vec2::new(2.0, 4.0), Vec2::new(3.0, 6.0)]);
+    /// assert_eq!(Vec2::linspace(Vec2::ZERO, Vec2::RIGHT, 1).collect::<Vec<_>>(), [Vec2::ZERO]);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn linspace(a: Vec2, b: Vec2, n: usize) -> impl ExactSizeIterator<Item = Vec2> + Clone {
+        let step = (b - a) / (n.saturating_sub(1).max(1) as f32);
+        (0..n).map(move |i| {
+            if i + 1 == n && n > 1 {
+                b
+            } else {
+                a + step * i as f32
+            }
+        })
+    }
+
+    /// Returns points from `a` towards `b`, `step_len` apart, starting at `a` and stopping at (or before) `b`.
+    /// ```
+    /// # use vecto::Vec2;
+    /// let points: Vec<_> = Vec2::steps_by(Vec2::ZERO, Vec2::new(0.0, 5.0), 2.0).collect();
+    /// assert_eq!(points, [Vec2::ZERO, Vec2::new(0.0, 2.0), Vec2::new(0.0, 4.0)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `step_len` is not positive.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn steps_by(a: Vec2, b: Vec2, step_len: f32) -> impl Iterator<Item = Vec2> + Clone {
+        assert!(step_len > 0.0, "step_len must be positive");
+        let distance = a.distance_to(&b);
+        let step = (b - a).normalized() * step_len;
+        (0..=(distance / step_len) as usize).map(move |i| a + step * i as f32)
+    }
+
+    /// Wraps `self` into `bounds`, as in a world where leaving one edge re-enters from the opposite edge.
+    /// ```
+    /// # use vecto::{Rect2, Vec2};
+    /// let world = Rect2::new(Vec2::ZERO, Vec2::new(100.0, 50.0));
+    /// assert_eq!(Vec2::new(105.0, -10.0).wrap(world), Vec2::new(5.0, 40.0));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn wrap(self, bounds: Rect2<f32>) -> Self {
+        let p = self - bounds.position;
+        bounds.position + Vec2::new(p.x.rem_euclid(bounds.size.x), p.y.rem_euclid(bounds.size.y))
+    }
+
+    /// Returns the shortest offset from `self` to `to` in a world that wraps around `bounds`, which may cross an edge.
+    #[inline]
+    fn wrapped_delta_to(self, to: Self, bounds: Rect2<f32>) -> Self {
+        let half = bounds.size / 2.0;
+        let d = to - self + half;
+        Vec2::new(d.x.rem_euclid(bounds.size.x), d.y.rem_euclid(bounds.size.y)) - half
+    }
+
+    /// Returns the distance from `self` to `to` in a world that wraps around `bounds`, taking the shortest way, which may cross an edge.
+    /// ```
+    /// # use vecto::{Rect2, Vec2};
+    /// let world = Rect2::new(Vec2::ZERO, Vec2::new(100.0, 50.0));
+    /// assert_eq!(Vec2::new(95.0, 10.0).wrapped_distance_to(Vec2::new(5.0, 10.0), world), 10.0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn wrapped_distance_to(self, to: Self, bounds: Rect2<f32>) -> f32 {
+        self.wrapped_delta_to(to, bounds).length()
+    }
+
+    /// Returns the normalized direction from `self` to `to` in a world that wraps around `bounds`, taking the shortest way, which may cross an edge.
+    /// ```
+    /// # use vecto::{Rect2, Vec2};
+    /// let world = Rect2::new(Vec2::ZERO, Vec2::new(100.0, 50.0));
+    /// assert_eq!(Vec2::new(10.0, 45.0).wrapped_direction_to(Vec2::new(10.0, 5.0), world), Vec2::DOWN);
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn wrapped_direction_to(self, to: Self, bounds: Rect2<f32>) -> Self {
+        self.wrapped_delta_to(to, bounds).normalized()
+    }
+
+    /// Inverse of [`Vec2::bilerp`]: finds the `uv` at which the quad with corners `p00`, `p10`, `p01` and `p11` reaches `point`.
+    ///
+    /// A `uv` outside of `0..=1` means `point` lies outside the quad. Returns [`None`] if no `uv` maps to `point`.
+    /// ```
+    /// # use vecto::{Vec2, Kinda};
+    /// let (a, b, c, d) = (Vec2::ZERO, Vec2::new(4.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(2.0, 3.0));
+    /// let uv = Vec2::new(0.25, 0.75);
+    /// let p = Vec2::bilerp(a, b, c, d, uv);
+    /// assert!(Vec2::inverse_bilerp(a, b, c, d, p).unwrap().approx_eq(uv));
+    /// // the same quad, at a much smaller scale
+    /// let (a, b, c, d) = (a * 1e-4, b * 1e-4, c * 1e-4, d * 1e-4);
+    /// assert!(Vec2::inverse_bilerp(a, b, c, d, p * 1e-4).unwrap().approx_eq(uv));
+    /// ```
+    #[must_use]
+    pub fn inverse_bilerp(p00: Vec2, p10: Vec2, p01: Vec2, p11: Vec2, point: Vec2) -> Option<Vec2> {
+        let across = p10 - p00;
+        let down = p01 - p00;
+        let twist = p00 - p10 + p11 - p01;
+        let rel = point - p00;
+        // solve k2 v² + k1 v + k0 = 0
+        let k2 = twist.cross(&down);
+        let k1 = across.cross(&down) + rel.cross(&twist);
+        let k0 = rel.cross(&across);
+        let u_for = |v: f32| {
+            let (dx, dy) = (across.x + twist.x * v, across.y + twist.y * v);
+            if dx.abs() > dy.abs() {
+                (rel.x - down.x * v) / dx
+            } else {
+                (rel.y - down.y * v) / dy
+            }
+        };
+        // relative to the size of the quad, as k2 grows with the square of it
+        if k2.abs() <= f32::EPSILON * across.length_squared().max(down.length_squared()) {
+            // parallel edges, linear
+            let v = -k0 / k1;
+            return v.is_finite().then(|| Vec2::new(u_for(v), v));
+        }
+        let discriminant = k1 * k1 - 4.0 * k0 * k2;
+        if discriminant < 0.0 {
+            return None;
+        }
+        // the roots without cancellation, for nearly parallel edges
+        let q = -0.5 * (k1 + discriminant.sqrt().copysign(k1));
+        let (near, far) = (if q == 0.0 { 0.0 } else { k0 / q }, q / k2);
+        // try `(-k1 - √d) / 2k2` first, then `(-k1 + √d) / 2k2`
+        let (v0, v1) = if k1 < 0.0 { (near, far) } else { (far, near) };
+        let inside = |uv: &Vec2| (0.0..=1.0).contains(&uv.x) && (0.0..=1.0).contains(&uv.y);
+        let first = Vec2::new(u_for(v0), v0);
+        if inside(&first) {
+            return Some(first);
+        }
+        let second = Vec2::new(u_for(v1), v1);
+        Some(if inside(&second) { second } else { first })
+    }
 }
 
 impl Vector2<f64> {
@@ -104,12 +487,229 @@ impl Vector2<f64> {
     pub const DOWN: Vec2 = Vec2::new(0.0, 1.0);
 }
 
+impl<T: Copy + PartialOrd + std::ops::Add<T, Output = T>> Vector2<T> {
+    /// Returns the point inside (or on the edge of) `rect` nearest to `self`. See [`Rect2::clamp_point`].
+    /// ```
+    /// # use vecto::{Rect2, Vec2};
+    /// let screen = Rect2::new(Vec2::ZERO, Vec2::new(640.0, 480.0));
+    /// assert_eq!(Vec2::new(-20.0, 500.0).clamp_to(screen), Vec2::new(0.0, 480.0));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn clamp_to(self, rect: Rect2<T>) -> Self {
+        rect.clamp_point(self)
+    }
+}
+
+impl<T: Copy + std::ops::Mul<T, Output = T> + std::ops::Add<T, Output = T>> Vector2<T> {
+    /// Returns the vector sheared along X by `x_shear` times its Y, and along Y by `y_shear` times its X.
+    /// ```
+    /// # use vecto::Vec2;
+    /// // italics
+    /// assert_eq!(Vec2::new(1.0, -2.0).sheared(-0.25, 0.0), Vec2::new(1.5, -2.0));
+    /// assert_eq!(Vec2::new(2.0, 1.0).sheared(0.0, 0.5), Vec2::new(2.0, 2.0));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn sheared(self, x_shear: T, y_shear: T) -> Self {
+        Self::new(self.x + x_shear * self.y, self.y + y_shear * self.x)
+    }
+}
+
+impl<T> Vector2<T>
+where
+    T: Copy
+        + std::ops::Mul<T, Output = T>
+        + std::ops::Add<T, Output = T>
+        + std::ops::Sub<T, Output = T>
+        + std::ops::Div<T, Output = T>,
+{
+    /// Multiplies `self` and `with` as complex numbers `x + yi`.
+    ///
+    /// Rotates `self` by the angle of `with` and scales it by the length of `with`, without any trigonometry.
+    /// With unit vectors from [`Vector2::from_angle`], chaining these composes rotations.
+    /// ```
+    /// # use vecto::{Kinda, Vec2};
+    /// let (a, b) = (Vec2::from_angle(0.3), Vec2::from_angle(0.4));
+    /// assert!(a.complex_mul(b).approx_eq(Vec2::from_angle(0.7)));
+    /// assert_eq!(Vec2::new(2.0, 0.0).complex_mul(Vec2::DOWN), Vec2::new(0.0, 2.0));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn complex_mul(self, with: Self) -> Self {
+        Self::new(
+            self.x * with.x - self.y * with.y,
+            self.x * with.y + self.y * with.x,
+        )
+    }
+
+    /// Divides `self` by `by` as complex numbers `x + yi`: the inverse of [`Vector2::complex_mul`].
+    ///
+    /// Rotates `self` back by the angle of `by` and divides it by the length of `by`.
+    /// ```
+    /// # use vecto::{Kinda, Vec2};
+    /// let (a, b) = (Vec2::new(1.0, 2.0), Vec2::new(3.0, -1.0));
+    /// assert!(a.complex_mul(b).complex_div(b).approx_eq(a));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn complex_div(self, by: Self) -> Self {
+        let length_squared = by.x * by.x + by.y * by.y;
+        Self::new(
+            (self.x * by.x + self.y * by.y) / length_squared,
+            (self.y * by.x - self.x * by.y) / length_squared,
+        )
+    }
+}
+
+impl<T: Copy + std::ops::Mul<T, Output = T> + std::ops::Neg<Output = T>> Vector2<T> {
+    /// Returns `self × z`, the cross product of `self` with a vector of length `z` along the Z axis.
+    /// This is the same vector as [`Vector2::scalar_cross`], reversed.
+    /// ```
+    /// # use vecto::Vec2;
+    /// assert_eq!(Vec2::new(1.0, 2.0).cross_scalar(3.0), Vec2::new(6.0, -3.0));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn cross_scalar(self, z: T) -> Self {
+        Self::new(z * self.y, -(z * self.x))
+    }
+
+    /// Returns `z × self`, the cross product of a vector of length `z` along the Z axis with `self`.
+    ///
+    /// With `z` an angular velocity and `self` an offset from the center of rotation, this is the resulting linear velocity.
+    /// ```
+    /// # use vecto::Vec2;
+    /// let (angular_velocity, arm) = (2.0, Vec2::new(3.0, 0.0));
+    /// assert_eq!(arm.scalar_cross(angular_velocity), Vec2::new(0.0, 6.0));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn scalar_cross(self, z: T) -> Self {
+        Self::new(-(z * self.y), z * self.x)
+    }
+}
+
 impl<T: std::ops::Neg<Output = T>> Vector2<T> {
     /// Returns a perpendicular vector, rotated 90 degrees counter-clockwise, with the same length.
     #[must_use = "Does not modify in place."]
+    #[inline]
     pub fn orthogonal(self) -> Self {
         Self::new(self.y, -self.x)
     }
+
+    /// Returns the vector rotated 90 degrees clockwise (on screen, as Y is down), exactly.
+    /// ```
+    /// # use vecto::{IVec2, Vec2};
+    /// assert_eq!(Vec2::RIGHT.rotate_cw(), Vec2::DOWN);
+    /// // rotating a tetromino's cells about the origin
+    /// let cells = [IVec2::new(0, 0), IVec2::new(1, 0), IVec2::new(2, 0), IVec2::new(2, 1)];
+    /// assert_eq!(cells.map(IVec2::rotate_cw), [IVec2::new(0, 0), IVec2::new(0, 1), IVec2::new(0, 2), IVec2::new(-1, 2)]);
+    /// assert_eq!(cells.map(|c| c.rotate_cw().rotate_ccw()), cells);
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn rotate_cw(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Returns the vector rotated 90 degrees counter-clockwise (on screen, as Y is down), exactly. Same as [`Vector2::orthogonal`].
+    /// ```
+    /// # use vecto::Vec2;
+    /// assert_eq!(Vec2::RIGHT.rotate_ccw(), Vec2::UP);
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn rotate_ccw(self) -> Self {
+        self.orthogonal()
+    }
+
+    /// Returns the vector rotated 180 degrees, exactly; the same as negating it.
+    /// ```
+    /// # use vecto::IVec2;
+    /// assert_eq!(IVec2::new(2, -1).rotate_180(), IVec2::new(-2, 1));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn rotate_180(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T> Vector2<T>
+where
+    T: Copy
+        + std::ops::Mul<T, Output = T>
+        + std::ops::Add<T, Output = T>
+        + std::ops::Sub<T, Output = T>,
+{
+    /// Returns the cross product of `self` and `with`.
+    /// Positive if `with` is clockwise of `self` (Y is down).
+    /// ```
+    /// # use vecto::IVec2;
+    /// assert_eq!(IVec2::new(1, 0).cross(&IVec2::new(0, 1)), 1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// With unsigned components, in debug builds, if the result would be negative.
+    #[inline]
+    pub fn cross(&self, with: &Self) -> T {
+        self.x * with.y - self.y * with.x
+    }
+
+    /// Returns the perpendicular dot product of `self` and `with`, the dot product of [`Vector2::orthogonal`] with `with`, negated.
+    /// This is the same as [`Vector2::cross`].
+    /// ```
+    /// # use vecto::Vec2;
+    /// let (a, b) = (Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0));
+    /// assert_eq!(a.perp_dot(&b), a.cross(&b));
+    /// assert_eq!(a.perp_dot(&b), -a.orthogonal().dot(&b));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Like [`Vector2::cross`], with unsigned components, in debug builds, if the result would be negative.
+    #[must_use]
+    #[inline]
+    pub fn perp_dot(&self, with: &Self) -> T {
+        self.cross(with)
+    }
+
+    /// Returns the squared distance from `self` to `to`. Faster than [`Self::distance_to`].
+    /// ```
+    /// # use vecto::IVec2;
+    /// assert_eq!(IVec2::new(1, 1).distance_squared_to(&IVec2::new(4, 5)), 25);
+    /// // the differences are taken the larger minus the smaller, so unsigned components don't underflow
+    /// assert_eq!(vecto::Vector2::<u32>::new(1, 5).distance_squared_to(&vecto::Vector2::new(4, 1)), 25);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn distance_squared_to(&self, to: &Self) -> T
+    where
+        T: PartialOrd,
+    {
+        let difference = |a: T, b: T| if a > b { a - b } else { b - a };
+        let (dx, dy) = (difference(self.x, to.x), difference(self.y, to.y));
+        dx * dx + dy * dy
+    }
+
+    /// Returns the dot product of `self` and `with`.
+    #[inline]
+    pub fn dot(&self, with: &Self) -> T {
+        self.x * with.x + self.y * with.y
+    }
+
+    /// Returns the squared length of `self`. Faster than [`Self::length`].
+    /// ```
+    /// # use vecto::Vec2;
+    /// assert_eq!(Vec2::splat(10.0).length_squared(), 200.0);
+    /// assert_eq!(vecto::IVec2::new(3, 4).length_squared(), 25);
+    /// ```
+    #[inline]
+    pub fn length_squared(&self) -> T {
+        self.x * self.x + self.y * self.y
+    }
 }
 
 impl<T: FloatAlone> Vector2<T> {
@@ -122,12 +722,40 @@ impl<T: FloatAlone> Vector2<T> {
     /// assert_eq!(Vec2::RIGHT.angle(), 0.0);
     /// assert!(Vec2::from_angle(PI / 2.0).approx_eq(Vec2::new(0.0, 1.0)));
     /// ```
+    #[inline]
     pub fn from_angle(angle: T) -> Self {
         Self::new(angle.cos(), angle.sin())
     }
 
+    /// Linearly interpolates from `self` to `to` by `t`, where `t` of 0 gives `self` and 1 gives `to`.
+    /// ```
+    /// # use vecto::Vec2;
+    /// assert_eq!(Vec2::ZERO.lerp(Vec2::new(4.0, 2.0), 0.25), Vec2::new(1.0, 0.5));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn lerp(self, to: Self, t: T) -> Self {
+        self + (to - self) * t
+    }
+
+    /// Bilinearly interpolates within the quad with corners `p00`, `p10`, `p01` and `p11`, where `uv` of `(0, 0)` gives `p00` and `(1, 1)` gives `p11`.
+    /// ```
+    /// # use vecto::Vec2;
+    /// let (a, b, c, d) = (Vec2::ZERO, Vec2::new(4.0, 0.0), Vec2::new(0.0, 2.0), Vec2::new(2.0, 2.0));
+    /// assert_eq!(Vec2::bilerp(a, b, c, d, Vec2::new(1.0, 0.0)), b);
+    /// assert_eq!(Vec2::bilerp(a, b, c, d, Vec2::splat(0.5)), Vec2::new(1.5, 1.0));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn bilerp(p00: Self, p10: Self, p01: Self, p11: Self, uv: Self) -> Self {
+        let top = p00 + (p10 - p00) * uv.x;
+        let bottom = p01 + (p11 - p01) * uv.x;
+        top + (bottom - top) * uv.y
+    }
+
     /// Returns a new vector with all components in absolute values (i.e. positive).
     #[must_use = "Does not modify in place."]
+    #[inline]
     pub fn abs(self) -> Self {
         Self::new(self.x.abs(), self.y.abs())
     }
@@ -140,43 +768,27 @@ impl<T: FloatAlone> Vector2<T> {
     /// assert_eq!(Vec2::DOWN.angle(), PI / 2.0); // 90 degrees
     /// assert_eq!(Vec2::new(1.0, -1.0).angle(), -PI / 4.0); // -45 degrees
     /// ```
+    #[inline]
     pub fn angle(&self) -> T {
         self.y.atan2(self.x)
     }
 
-    /// Returns the cross product of `self` and `with`.
-    pub fn cross(&self, with: &Self) -> T {
-        self.x * with.y - self.y * with.x
-    }
-
     /// Returns the distance from `self` to `to`.
+    #[inline]
     pub fn distance_to(&self, to: &Self) -> T {
         ((self.x - to.x) * (self.x - to.x) + (self.y - to.y) * (self.y - to.y)).sqrt()
     }
 
-    /// Returns the dot product of `self` and `with`.
-    pub fn dot(&self, with: &Self) -> T {
-        self.x * with.x + self.y * with.y
-    }
-
     /// Returns the length(magnitude) of `self`.
     /// ```
     /// # use vecto::Vec2;
     /// assert_eq!(Vec2::splat(10.0).length(), 10.0 * 2.0f32.sqrt());
     /// ```
+    #[inline]
     pub fn length(&self) -> T {
         (self.x * self.x + self.y * self.y).sqrt()
     }
 
-    /// Returns the squared length of `self`. Faster than [`Self::length`].
-    /// ```
-    /// # use vecto::Vec2;
-    /// assert_eq!(Vec2::splat(10.0).length_squared(), 200.0);
-    /// ```
-    pub fn length_squared(&self) -> T {
-        self.x * self.x + self.y * self.y
-    }
-
     /// Returns the vector with a new maximum length.
     /// ```
     /// # use vecto::{Kinda, Vec2};
@@ -184,6 +796,7 @@ impl<T: FloatAlone> Vector2<T> {
     /// assert!(Vec2::splat(10.).limit_length(5.0).approx_eq(Vec2::splat(1.0 / 2.0f32.sqrt() * 5.0)));
     /// ```
     #[must_use = "Does not modify in place."]
+    #[inline]
     pub fn limit_length(self, len: T) -> Self {
         let l = self.length();
         if l > unsafe { T::zero() } && len < l {
@@ -192,8 +805,49 @@ impl<T: FloatAlone> Vector2<T> {
         self
     }
 
+    /// Returns the vector with a new maximum length. Alias of [`Self::limit_length`].
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn clamp_length_max(self, max: T) -> Self {
+        self.limit_length(max)
+    }
+
+    /// Returns the vector with a new minimum length.
+    ///
+    /// The zero vector has no direction to grow in, so it is returned unchanged.
+    /// ```
+    /// # use vecto::Vec2;
+    /// assert_eq!(Vec2::new(0.5, 0.0).clamp_length_min(2.0), Vec2::new(2.0, 0.0));
+    /// assert_eq!(Vec2::new(0.0, 3.0).clamp_length_min(2.0), Vec2::new(0.0, 3.0));
+    /// assert_eq!(Vec2::ZERO.clamp_length_min(2.0), Vec2::ZERO);
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn clamp_length_min(self, min: T) -> Self {
+        let l = self.length();
+        if l > unsafe { T::zero() } && l < min {
+            return (self / l) * min;
+        }
+        self
+    }
+
+    /// Returns the vector with its length clamped between `min` and `max`.
+    ///
+    /// The zero vector has no direction to grow in, so it is returned unchanged.
+    /// ```
+    /// # use vecto::Vec2;
+    /// assert_eq!(Vec2::new(0.0, 0.5).clamp_length(1.0, 4.0), Vec2::new(0.0, 1.0));
+    /// assert_eq!(Vec2::new(0.0, 9.0).clamp_length(1.0, 4.0), Vec2::new(0.0, 4.0));
+    /// assert_eq!(Vec2::ZERO.clamp_length(1.0, 4.0), Vec2::ZERO);
+    /// ```
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn clamp_length(self, min: T, max: T) -> Self {
+        self.clamp_length_min(min).limit_length(max)
+    }
+
     /// Returns the result of scaling the vector to unit length.
-    /// Equivalent to v / v.length().
+    /// Equivalent to `v / v.length()`.
     ///
     /// Note: This function may struggle with denormal values.
     /// ```
@@ -202,6 +856,7 @@ impl<T: FloatAlone> Vector2<T> {
     /// assert!(Vec2::splat(1.0).normalized().approx_eq(Vec2::splat(0.5f32.sqrt())));
     /// ```
     #[must_use = "Does not modify in place."]
+    #[inline]
     pub fn normalized(self) -> Self {
         let l = self.length_squared();
         if l != unsafe { T::zero() } {
@@ -221,6 +876,7 @@ impl<T: FloatAlone> Vector2<T> {
     /// assert!(v.rotated(TAU / 2.0).approx_eq(v.rotated(TAU / -2.0)));
     /// ```
     #[must_use = "Does not modify in place."]
+    #[inline]
     pub fn rotated(self, angle: T) -> Self {
         Vector2::new(
             self.x * angle.cos() - self.y * angle.sin(),
@@ -232,12 +888,14 @@ impl<T: FloatAlone> Vector2<T> {
 impl<T: Rounding> Vector2<T> {
     /// Returns a new vector with all components rounded up (towards positive infinity).
     #[must_use = "Does not modify in place."]
+    #[inline]
     pub fn ceil(self) -> Self {
         Self::new(self.x.ceil(), self.y.ceil())
     }
 
     /// Returns a new vector with all components rounded down (towards negative infinity).
     #[must_use = "Does not modify in place."]
+    #[inline]
     pub fn floor(self) -> Self {
         Self::new(self.x.floor(), self.y.floor())
     }
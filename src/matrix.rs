@@ -0,0 +1,203 @@
+use crate::Vector2;
+use core::ops::{Add, AddAssign, Mul, Sub};
+
+/// A 2x2 matrix, stored as its two columns.
+/// ```
+/// # use vecto::{Matrix2, Vec2};
+/// // rotate 90 degrees
+/// let m = Matrix2::new(Vec2::new(0.0, 1.0), Vec2::new(-1.0, 0.0));
+/// assert_eq!(m * Vec2::RIGHT, Vec2::DOWN);
+/// assert_eq!(m.determinant(), 1.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Default, Hash, Eq, Ord, Debug)]
+#[repr(C)]
+pub struct Matrix2<T> {
+    /// The first column, where the X axis goes.
+    pub x: Vector2<T>,
+    /// The second column, where the Y axis goes.
+    pub y: Vector2<T>,
+}
+
+impl<T> Matrix2<T> {
+    /// Construct a new [`Matrix2`] from its columns.
+    #[must_use]
+    #[inline]
+    pub const fn new(x: Vector2<T>, y: Vector2<T>) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the transposed matrix, with rows and columns swapped.
+    #[must_use = "Does not modify in place."]
+    #[inline]
+    pub fn transposed(self) -> Self {
+        Self::new(
+            Vector2::new(self.x.x, self.y.x),
+            Vector2::new(self.x.y, self.y.y),
+        )
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T> + Sub<T, Output = T>> Matrix2<T> {
+    /// Returns the determinant, the signed area scale factor of the matrix.
+    #[must_use]
+    #[inline]
+    pub fn determinant(&self) -> T {
+        self.x.x * self.y.y - self.y.x * self.x.y
+    }
+}
+
+macro_rules! float {
+    ($t:ty) => {
+        impl Matrix2<$t> {
+            /// The identity matrix.
+            pub const IDENTITY: Self = Self::new(Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0));
+
+            /// Returns the eigenvalues of a symmetric matrix, largest first.
+            ///
+            /// Only the average of the two off-diagonal entries is used, so the matrix is assumed to be symmetric
+            /// (like covariance or inertia matrices).
+            /// ```
+            /// # use vecto::{Matrix2, Vector2};
+            #[doc = concat!("let m = Matrix2::<", stringify!($t), ">::new(Vector2::new(2.0, 1.0), Vector2::new(1.0, 2.0));")]
+            /// assert_eq!(m.eigenvalues(), (3.0, 1.0));
+            /// ```
+            #[must_use]
+            #[inline]
+            pub fn eigenvalues(&self) -> ($t, $t) {
+                let (a, b, d) = (self.x.x, (self.x.y + self.y.x) / 2.0, self.y.y);
+                let mean = (a + d) / 2.0;
+                let spread = ((a - d) / 2.0).hypot(b);
+                (mean + spread, mean - spread)
+            }
+
+            /// Returns the unit eigenvectors of a symmetric matrix, matching the order of [`Matrix2::eigenvalues`].
+            /// The second is the first rotated by 90 degrees.
+            ///
+            /// For a covariance matrix, the first is the principal axis of the data.
+            /// ```
+            /// # use vecto::{Matrix2, Vector2};
+            #[doc = concat!("let m = Matrix2::<", stringify!($t), ">::new(Vector2::new(2.0, 1.0), Vector2::new(1.0, 2.0));")]
+            /// let (major, minor) = m.eigenvectors();
+            /// let s = 0.5f32.sqrt() as _;
+            /// assert!((major - Vector2::splat(s)).length() < 1e-6);
+            /// assert!((minor - Vector2::new(s, -s)).length() < 1e-6);
+            /// assert_eq!(m * major, major * 3.0);
+            /// ```
+            #[must_use]
+            #[inline]
+            pub fn eigenvectors(&self) -> (Vector2<$t>, Vector2<$t>) {
+                let (b, d) = ((self.x.y + self.y.x) / 2.0, self.y.y);
+                let (largest, _) = self.eigenvalues();
+                let major = if b == 0.0 {
+                    // already diagonal
+                    if self.x.x >= d {
+                        Vector2::new(1.0, 0.0)
+                    } else {
+                        Vector2::new(0.0, 1.0)
+                    }
+                } else {
+                    Vector2::new(largest - d, b).normalized()
+                };
+                (major, major.orthogonal())
+            }
+        }
+    };
+}
+float!(f32);
+float!(f64);
+
+impl<T: Copy + Mul<T, Output = T> + Add<T, Output = T>> Mul<Vector2<T>> for Matrix2<T> {
+    type Output = Vector2<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector2<T>) -> Vector2<T> {
+        Vector2::new(
+            self.x.x * rhs.x + self.y.x * rhs.y,
+            self.x.y * rhs.x + self.y.y * rhs.y,
+        )
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T> + Add<T, Output = T>> Matrix2<T> {
+    /// Multiplies every vector in `points` by the matrix, in place.
+    ///
+    /// Same as `*p = m * *p` for each point, but with the entries kept in registers so the loop vectorizes.
+    /// ```
+    /// # use vecto::{Matrix2, Vec2};
+    /// let quarter_turn = Matrix2::new(Vec2::new(0.0, 1.0), Vec2::new(-1.0, 0.0));
+    /// let mut points = [Vec2::RIGHT, Vec2::DOWN];
+    /// quarter_turn.transform_slice(&mut points);
+    /// assert_eq!(points, [Vec2::DOWN, Vec2::LEFT]);
+    /// ```
+    #[inline]
+    pub fn transform_slice(&self, points: &mut [Vector2<T>]) {
+        let (xx, xy, yx, yy) = (self.x.x, self.x.y, self.y.x, self.y.y);
+        for p in points {
+            let (x, y) = (p.x, p.y);
+            p.x = xx * x + yx * y;
+            p.y = xy * x + yy * y;
+        }
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T> + Add<T, Output = T>> Mul for Matrix2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self * rhs.x, self * rhs.y)
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T>> Mul<T> for Matrix2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Add<T, Output = T>> Add for Matrix2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: AddAssign<T>> AddAssign for Matrix2<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: Sub<T, Output = T>> Sub for Matrix2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T>> Vector2<T> {
+    /// Returns the outer product of `self` and `with`, the matrix `self * withᵀ`.
+    /// ```
+    /// # use vecto::{Matrix2, Vec2};
+    /// let m = Vec2::new(1.0, 2.0).outer(Vec2::new(3.0, 4.0));
+    /// assert_eq!(m, Matrix2::new(Vec2::new(3.0, 6.0), Vec2::new(4.0, 8.0)));
+    /// // accumulating a covariance matrix
+    /// let points = [Vec2::new(-1.0, -1.0), Vec2::new(1.0, 1.0)];
+    /// let covariance = points.iter().fold(Matrix2::default(), |m, p| m + p.outer(*p)) * 0.5;
+    /// assert_eq!(covariance, Matrix2::new(Vec2::splat(1.0), Vec2::splat(1.0)));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn outer(self, with: Self) -> Matrix2<T> {
+        Matrix2::new(self * with.x, self * with.y)
+    }
+}
@@ -0,0 +1,92 @@
+use crate::Vec2;
+
+/// A point with velocity, and limits on its speed and acceleration, moved by forces (on a unit mass).
+///
+/// Forces applied during a frame add up, then [`KinematicBody2::step`] integrates them (with semi-implicit Euler) and clears them.
+/// ```
+/// # use vecto::{Vec2, motion::KinematicBody2};
+/// let mut body = KinematicBody2::new(Vec2::ZERO, 10.0, 5.0);
+/// body.apply_force(Vec2::new(100.0, 0.0));
+/// body.step(1.0);
+/// assert_eq!(body.velocity, Vec2::new(5.0, 0.0)); // limited by the acceleration
+/// for _ in 0..10 {
+///     body.apply_force(Vec2::new(100.0, 0.0));
+///     body.step(1.0);
+/// }
+/// assert_eq!(body.velocity, Vec2::new(10.0, 0.0)); // limited by the speed
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct KinematicBody2 {
+    /// The current position.
+    pub position: Vec2,
+    /// The current velocity.
+    pub velocity: Vec2,
+    /// The fastest it can go.
+    pub max_speed: f32,
+    /// The most its velocity can change per time unit.
+    pub max_acceleration: f32,
+    force: Vec2,
+}
+
+impl KinematicBody2 {
+    /// Construct a new [`KinematicBody2`] at rest at `position`.
+    #[must_use]
+    pub const fn new(position: Vec2, max_speed: f32, max_acceleration: f32) -> Self {
+        Self {
+            position,
+            velocity: Vec2::ZERO,
+            max_speed,
+            max_acceleration,
+            force: Vec2::ZERO,
+        }
+    }
+
+    /// Adds `force` to the forces acting until the next [`KinematicBody2::step`].
+    pub fn apply_force(&mut self, force: Vec2) {
+        self.force += force;
+    }
+
+    /// Returns the sum of the forces applied since the last step.
+    #[must_use]
+    pub fn force(&self) -> Vec2 {
+        self.force
+    }
+
+    /// Moves `dt` forward in time: accelerates by the applied forces (up to the maximum acceleration),
+    /// limits the speed, moves, and clears the forces.
+    pub fn step(&mut self, dt: f32) {
+        let acceleration = self.force.limit_length(self.max_acceleration);
+        self.velocity = (self.velocity + acceleration * dt).limit_length(self.max_speed);
+        self.position += self.velocity * dt;
+        self.force = Vec2::ZERO;
+    }
+
+    /// Applies the force that would change the velocity to `desired` over the next `dt`.
+    /// The step still limits it to the maximum acceleration.
+    pub fn steer(&mut self, desired: Vec2, dt: f32) {
+        self.apply_force((desired - self.velocity) / dt);
+    }
+
+    /// Steers towards `target`, braking in time to stop on it given the maximum acceleration.
+    /// ```
+    /// # use vecto::{Vec2, motion::KinematicBody2};
+    /// let mut body = KinematicBody2::new(Vec2::ZERO, 4.0, 2.0);
+    /// let target = Vec2::new(20.0, 10.0);
+    /// for _ in 0..600 {
+    ///     body.arrive(target, 1.0 / 60.0);
+    ///     body.step(1.0 / 60.0);
+    /// }
+    /// assert!(body.position.distance_to(&target) < 1e-3);
+    /// assert!(body.velocity.length() < 1e-3);
+    /// ```
+    pub fn arrive(&mut self, target: Vec2, dt: f32) {
+        let offset = target - self.position;
+        let distance = offset.length();
+        // the fastest speed it can still stop from, without passing the target within this step
+        let speed = self
+            .max_speed
+            .min((2.0 * self.max_acceleration * distance).sqrt())
+            .min(distance / dt);
+        self.steer(offset.normalized() * speed, dt);
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{shape::Polyline, Vec2};
+
+/// What a [`PathFollower`] does when it reaches the end of its path.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum PathMode {
+    /// Stop at the end.
+    #[default]
+    Once,
+    /// Jump back to the start. Repeat the first vertex at the end of the path to close the loop.
+    Loop,
+    /// Turn around, going back and forth between the ends.
+    PingPong,
+}
+
+/// Moves along a [`Polyline`] at a constant speed.
+/// ```
+/// # use vecto::{Vec2, motion::{PathFollower, PathMode}, shape::Polyline};
+/// let route = Polyline::new(vec![Vec2::ZERO, Vec2::new(4.0, 0.0), Vec2::new(4.0, 2.0)]);
+/// let mut guard = PathFollower::new(route, 2.0, PathMode::PingPong);
+/// assert_eq!(guard.advance(1.0), Vec2::new(2.0, 0.0));
+/// assert_eq!(guard.direction(), Vec2::RIGHT);
+/// assert_eq!(guard.advance(2.0), Vec2::new(4.0, 2.0));
+/// assert_eq!(guard.advance(0.5), Vec2::new(4.0, 1.0)); // turned around
+/// assert_eq!(guard.direction(), Vec2::UP);
+/// assert_eq!(guard.remaining(), 5.0); // back to the start
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct PathFollower {
+    path: Polyline,
+    /// The distance along the path of each vertex.
+    distances: Vec<f32>,
+    /// How far it moves per time unit.
+    pub speed: f32,
+    /// What happens at the end.
+    pub mode: PathMode,
+    /// The distance moved since the start, wrapped to one lap (there and back, for [`PathMode::PingPong`]).
+    travelled: f32,
+}
+
+impl PathFollower {
+    /// Construct a new [`PathFollower`] at the start of `path`.
+    ///
+    /// # Panics
+    ///
+    /// If the path has no vertices.
+    #[must_use]
+    pub fn new(path: Polyline, speed: f32, mode: PathMode) -> Self {
+        assert!(!path.vertices.is_empty(), "path must have a vertex");
+        let distances = core::iter::once(0.0)
+            .chain(path.segments().scan(0.0, |total, (a, b)| {
+                *total += a.distance_to(&b);
+                Some(*total)
+            }))
+            .collect();
+        Self {
+            path,
+            distances,
+            speed,
+            mode,
+            travelled: 0.0,
+        }
+    }
+
+    /// Returns the path followed.
+    #[must_use]
+    pub fn path(&self) -> &Polyline {
+        &self.path
+    }
+
+    /// Returns the length of the path.
+    #[must_use]
+    pub fn length(&self) -> f32 {
+        self.distances.last().copied().unwrap_or_default()
+    }
+
+    /// Moves `dt` forward in time, and returns the new [`PathFollower::position`].
+    pub fn advance(&mut self, dt: f32) -> Vec2 {
+        let length = self.length();
+        let travelled = self.travelled + self.speed * dt;
+        self.travelled = match self.mode {
+            _ if length == 0.0 => 0.0,
+            PathMode::Once => travelled.clamp(0.0, length),
+            PathMode::Loop => travelled.rem_euclid(length),
+            PathMode::PingPong => travelled.rem_euclid(2.0 * length),
+        };
+        self.position()
+    }
+
+    /// Returns the distance from the start of the path to the current position.
+    #[must_use]
+    pub fn distance(&self) -> f32 {
+        let length = self.length();
+        if self.travelled > length {
+            2.0 * length - self.travelled
+        } else {
+            self.travelled
+        }
+    }
+
+    /// Returns true if it is heading back towards the start, in [`PathMode::PingPong`].
+    #[must_use]
+    pub fn is_returning(&self) -> bool {
+        self.travelled > self.length()
+    }
+
+    /// Returns the distance left to the end it is heading towards.
+    #[must_use]
+    pub fn remaining(&self) -> f32 {
+        if self.is_returning() {
+            self.distance()
+        } else {
+            self.length() - self.distance()
+        }
+    }
+
+    /// Returns true if it has stopped at the end, in [`PathMode::Once`].
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.mode == PathMode::Once && self.travelled >= self.length()
+    }
+
+    /// Returns the index of the segment containing `distance`, skipping empty ones.
+    fn segment(&self, distance: f32) -> usize {
+        self.distances
+            .partition_point(|&d| d <= distance)
+            .clamp(1, self.distances.len().max(2) - 1)
+            - 1
+    }
+
+    /// Returns the current position.
+    #[must_use]
+    pub fn position(&self) -> Vec2 {
+        let vertices = &self.path.vertices;
+        if vertices.len() == 1 {
+            return vertices[0];
+        }
+        let distance = self.distance();
+        let i = self.segment(distance);
+        let span = self.distances[i + 1] - self.distances[i];
+        if span == 0.0 {
+            return vertices[i];
+        }
+        vertices[i].lerp(vertices[i + 1], (distance - self.distances[i]) / span)
+    }
+
+    /// Returns the unit direction it is moving in, along the current segment.
+    /// Zero for a path of a single point.
+    #[must_use]
+    pub fn direction(&self) -> Vec2 {
+        let vertices = &self.path.vertices;
+        if vertices.len() == 1 {
+            return Vec2::ZERO;
+        }
+        let i = self.segment(self.distance());
+        let forward = (vertices[i + 1] - vertices[i]).normalized();
+        if self.is_returning() {
+            -forward
+        } else {
+            forward
+        }
+    }
+
+    /// Goes back to the start.
+    pub fn reset(&mut self) {
+        self.travelled = 0.0;
+    }
+}
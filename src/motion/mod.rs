@@ -0,0 +1,15 @@
+//! Animating and moving points over time.
+mod body;
+#[cfg(feature = "shapes")]
+mod follow;
+mod predict;
+mod spring;
+mod tween;
+pub use body::KinematicBody2;
+#[cfg(feature = "shapes")]
+pub use follow::{PathFollower, PathMode};
+#[cfg(feature = "shapes")]
+pub use predict::time_of_impact;
+pub use predict::{closest_approach, intercept_direction, intercept_time};
+pub use spring::Spring2;
+pub use tween::Tween;
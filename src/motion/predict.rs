@@ -0,0 +1,131 @@
+#[cfg(feature = "shapes")]
+use crate::shape::Circle;
+use crate::Vec2;
+
+/// Returns the earliest time at which a projectile fired from `shooter` at `projectile_speed` can hit a target
+/// at `target` moving with constant velocity `target_velocity`, or [`None`] if it can never catch up.
+/// ```
+/// # use vecto::{Vec2, motion::intercept_time};
+/// // a target 10 away, crossing at 3 units per second, and a projectile at 5
+/// let t = intercept_time(Vec2::ZERO, Vec2::new(10.0, 0.0), Vec2::new(0.0, 3.0), 5.0).unwrap();
+/// assert_eq!(t, 2.5);
+/// // too fast to catch
+/// assert_eq!(intercept_time(Vec2::ZERO, Vec2::new(10.0, 0.0), Vec2::new(6.0, 0.0), 5.0), None);
+/// ```
+#[must_use]
+pub fn intercept_time(
+    shooter: Vec2,
+    target: Vec2,
+    target_velocity: Vec2,
+    projectile_speed: f32,
+) -> Option<f32> {
+    let offset = target - shooter;
+    // |offset + velocity * t| = speed * t
+    let a = target_velocity.length_squared() - projectile_speed * projectile_speed;
+    let b = 2.0 * offset.dot(&target_velocity);
+    let c = offset.length_squared();
+    if c == 0.0 {
+        return Some(0.0);
+    }
+    if a.abs() <= f32::EPSILON * projectile_speed * projectile_speed {
+        // as fast as the target, so only a target coming closer can be hit
+        let t = -c / b;
+        return (t > 0.0).then_some(t);
+    }
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    let (t1, t2) = ((-b - root) / (2.0 * a), (-b + root) / (2.0 * a));
+    let (first, second) = (t1.min(t2), t1.max(t2));
+    if first > 0.0 {
+        Some(first)
+    } else if second > 0.0 {
+        Some(second)
+    } else {
+        None
+    }
+}
+
+/// Returns the unit direction to fire in to hit a moving target, using [`intercept_time`], or [`None`] if it can never catch up.
+/// ```
+/// # use vecto::{Kinda, Vec2, motion::intercept_direction};
+/// let aim = intercept_direction(Vec2::ZERO, Vec2::new(10.0, 0.0), Vec2::new(0.0, 3.0), 5.0).unwrap();
+/// assert!(aim.approx_eq(Vec2::new(0.8, 0.6))); // leads the target
+/// ```
+#[must_use]
+pub fn intercept_direction(
+    shooter: Vec2,
+    target: Vec2,
+    target_velocity: Vec2,
+    projectile_speed: f32,
+) -> Option<Vec2> {
+    let t = intercept_time(shooter, target, target_velocity, projectile_speed)?;
+    Some((target + target_velocity * t - shooter).normalized())
+}
+
+/// Returns when two points moving with constant velocities are closest, from now on, and how far apart they are then,
+/// as `(time, distance)`.
+/// ```
+/// # use vecto::{Vec2, motion::closest_approach};
+/// // a missile flying past a ship
+/// let (time, distance) = closest_approach(Vec2::ZERO, Vec2::new(2.0, 0.0), Vec2::new(10.0, 3.0), Vec2::ZERO);
+/// assert_eq!((time, distance), (5.0, 3.0));
+/// // moving apart, so now is the closest
+/// let (time, distance) = closest_approach(Vec2::ZERO, Vec2::LEFT, Vec2::new(4.0, 0.0), Vec2::ZERO);
+/// assert_eq!((time, distance), (0.0, 4.0));
+/// ```
+#[must_use]
+pub fn closest_approach(a: Vec2, a_velocity: Vec2, b: Vec2, b_velocity: Vec2) -> (f32, f32) {
+    let offset = b - a;
+    let velocity = b_velocity - a_velocity;
+    let speed = velocity.length_squared();
+    let time = if speed == 0.0 {
+        0.0
+    } else {
+        (-offset.dot(&velocity) / speed).max(0.0)
+    };
+    (time, (offset + velocity * time).length())
+}
+
+/// Returns when two circles moving with constant velocities first touch, from now on, and the unit contact normal
+/// pointing from `a` towards `b`, or [`None`] if they never do. Circles already overlapping touch at time 0.
+/// ```
+/// # use vecto::{Vec2, motion::time_of_impact, shape::Circle};
+/// let ball = Circle::new(Vec2::ZERO, 1.0);
+/// let wall_post = Circle::new(Vec2::new(10.0, 0.0), 2.0);
+/// let (time, normal) = time_of_impact(ball, Vec2::new(7.0, 0.0), wall_post, Vec2::ZERO).unwrap();
+/// assert_eq!((time, normal), (1.0, Vec2::RIGHT));
+/// // passes by
+/// assert_eq!(time_of_impact(ball, Vec2::new(7.0, 0.0), Circle::new(Vec2::new(10.0, 4.0), 2.0), Vec2::ZERO), None);
+/// ```
+#[must_use]
+#[cfg(feature = "shapes")]
+pub fn time_of_impact(
+    a: Circle,
+    a_velocity: Vec2,
+    b: Circle,
+    b_velocity: Vec2,
+) -> Option<(f32, Vec2)> {
+    let offset = b.center - a.center;
+    let velocity = b_velocity - a_velocity;
+    let reach = a.radius + b.radius;
+    // |offset + velocity * t| = reach
+    let c = offset.length_squared() - reach * reach;
+    if c <= 0.0 {
+        return Some((0.0, offset.normalized()));
+    }
+    let closing = 2.0 * offset.dot(&velocity);
+    if closing >= 0.0 {
+        // moving apart
+        return None;
+    }
+    let discriminant = closing * closing - 4.0 * velocity.length_squared() * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    // the smaller root, without cancellation
+    let time = 2.0 * c / (-closing + discriminant.sqrt());
+    Some((time, (offset + velocity * time) / reach))
+}
@@ -0,0 +1,99 @@
+use crate::Vec2;
+use std::f32::consts::TAU;
+
+/// A damped spring pulling a point towards a (possibly moving) target, for motion that overshoots and settles.
+///
+/// Each step is solved exactly, assuming the target stays put during it, so it is stable for any step size.
+/// ```
+/// # use vecto::{Vec2, motion::Spring2};
+/// let mut camera = Spring2::new(Vec2::ZERO, 2.0, 0.5);
+/// let player = Vec2::new(100.0, 0.0);
+/// let mut overshot = false;
+/// for _ in 0..120 {
+///     overshot |= camera.update(player, 1.0 / 60.0).x > 100.0;
+/// }
+/// assert!(overshot);
+/// assert!((camera.position - player).length() < 1.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Spring2 {
+    /// The current position.
+    pub position: Vec2,
+    /// The current velocity, in units per time step unit.
+    pub velocity: Vec2,
+    /// How fast it oscillates without damping, in cycles per time unit.
+    pub frequency: f32,
+    /// How quickly oscillation dies down: below 1 overshoots, 1 (critically damped) settles fastest without overshooting,
+    /// and above 1 approaches more slowly.
+    pub damping_ratio: f32,
+}
+
+impl Spring2 {
+    /// Construct a new [`Spring2`] at rest at `position`.
+    #[must_use]
+    pub const fn new(position: Vec2, frequency: f32, damping_ratio: f32) -> Self {
+        Self {
+            position,
+            velocity: Vec2::ZERO,
+            frequency,
+            damping_ratio,
+        }
+    }
+
+    /// Construct a new [`Spring2`] at rest at `position`, from the `stiffness` and `damping` of a spring pulling a unit mass
+    /// (accelerating by `-stiffness * offset - damping * velocity`).
+    /// ```
+    /// # use vecto::{Vec2, motion::Spring2};
+    /// let spring = Spring2::with_stiffness(Vec2::ZERO, 4.0, 4.0);
+    /// assert_eq!(spring.damping_ratio, 1.0);
+    /// ```
+    #[must_use]
+    pub fn with_stiffness(position: Vec2, stiffness: f32, damping: f32) -> Self {
+        let angular = stiffness.sqrt();
+        Self::new(position, angular / TAU, damping / (2.0 * angular))
+    }
+
+    /// Moves `dt` forward in time towards `target`, and returns the new position.
+    #[allow(clippy::similar_names)]
+    pub fn update(&mut self, target: Vec2, dt: f32) -> Vec2 {
+        let omega = self.frequency * TAU;
+        let zeta = self.damping_ratio;
+        // offset' = a * offset + b * velocity, velocity' = c * offset + d * velocity
+        let (a, b, c, d) = if omega <= 0.0 {
+            (1.0, dt, 0.0, 1.0)
+        } else if (zeta - 1.0).abs() < 1e-3 {
+            let decay = (-omega * dt).exp();
+            (
+                (1.0 + omega * dt) * decay,
+                dt * decay,
+                -omega * omega * dt * decay,
+                (1.0 - omega * dt) * decay,
+            )
+        } else if zeta < 1.0 {
+            let damped = omega * (1.0 - zeta * zeta).sqrt();
+            let decay = (-zeta * omega * dt).exp();
+            let (sin, cos) = (damped * dt).sin_cos();
+            (
+                decay * (cos + zeta * omega / damped * sin),
+                decay * sin / damped,
+                -decay * omega * omega / damped * sin,
+                decay * (cos - zeta * omega / damped * sin),
+            )
+        } else {
+            let root = omega * (zeta * zeta - 1.0).sqrt();
+            let (r1, r2) = (-zeta * omega + root, -zeta * omega - root);
+            let (e1, e2) = ((r1 * dt).exp(), (r2 * dt).exp());
+            let gap = r1 - r2;
+            (
+                (r1 * e2 - r2 * e1) / gap,
+                (e1 - e2) / gap,
+                r1 * r2 * (e2 - e1) / gap,
+                (r1 * e1 - r2 * e2) / gap,
+            )
+        };
+        let offset = self.position - target;
+        self.position = target + offset * a + self.velocity * b;
+        self.velocity = offset * c + self.velocity * d;
+        self.position
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{ease::Easing, Vec2};
+
+/// Moves from one point to another over a fixed duration, along an [`Easing`] curve.
+/// ```
+/// # use vecto::{Vec2, ease::Easing, motion::Tween};
+/// let mut slide = Tween::new(Vec2::ZERO, Vec2::new(10.0, 0.0), 2.0, Easing::Linear);
+/// assert_eq!(slide.advance(0.5), Vec2::new(2.5, 0.0));
+/// assert_eq!(slide.advance(1.0), Vec2::new(7.5, 0.0));
+/// assert!(!slide.is_finished());
+/// assert_eq!(slide.advance(1.0), Vec2::new(10.0, 0.0)); // stops at the end
+/// assert!(slide.is_finished());
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Tween {
+    /// Where it starts.
+    pub from: Vec2,
+    /// Where it ends.
+    pub to: Vec2,
+    /// How long it takes, in the same unit as the time steps.
+    pub duration: f32,
+    /// The curve followed.
+    pub easing: Easing,
+    elapsed: f32,
+}
+
+impl Tween {
+    /// Construct a new [`Tween`] from `from` to `to`, lasting `duration`.
+    #[must_use]
+    pub const fn new(from: Vec2, to: Vec2, duration: f32, easing: Easing) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            easing,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Moves `dt` forward in time (or backwards, if negative), and returns the new [`Tween::value`].
+    /// Time stops at the start and the end.
+    pub fn advance(&mut self, dt: f32) -> Vec2 {
+        self.elapsed = (self.elapsed + dt).clamp(0.0, self.duration.max(0.0));
+        self.value()
+    }
+
+    /// Returns the current point.
+    #[must_use]
+    pub fn value(&self) -> Vec2 {
+        self.from.lerp_eased(self.to, self.progress(), self.easing)
+    }
+
+    /// Returns the time passed since the start.
+    #[must_use]
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Returns how far through the duration it is, from 0 to 1, before easing.
+    /// A tween with no duration is always finished.
+    #[must_use]
+    pub fn progress(&self) -> f32 {
+        if self.duration > 0.0 {
+            self.elapsed / self.duration
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns true if it has reached the end.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+
+    /// Goes back to the start.
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+}
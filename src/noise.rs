@@ -0,0 +1,254 @@
+//! Seeded, deterministic gradient noise.
+//! ```
+//! # use vecto::{Vec2, noise::{Fbm, Noise, Perlin}};
+//! let terrain = Fbm::new(Perlin::new(7), 5);
+//! let height = terrain.noise(Vec2::new(3.7, 1.2) / 16.0);
+//! assert!((-1.0..=1.0).contains(&height));
+//! assert_eq!(height, Fbm::new(Perlin::new(7), 5).noise(Vec2::new(3.7, 1.2) / 16.0));
+//! ```
+use crate::Vec2;
+
+/// Gradient noise sampled over the plane.
+pub trait Noise {
+    /// Returns the noise at `p`, in roughly `-1..=1`.
+    fn noise(&self, p: Vec2) -> f32;
+
+    /// Returns the gradient of the noise at `p`, pointing towards increasing values, computed with central differences.
+    /// ```
+    /// # use vecto::{Vec2, noise::{Noise, Perlin}};
+    /// let perlin = Perlin::new(3);
+    /// let p = Vec2::new(0.4, 1.3);
+    /// let step = perlin.gradient(p).normalized() * 0.01;
+    /// assert!(perlin.noise(p + step) > perlin.noise(p));
+    /// // Perlin noise repeats every 256 units, so far away the gradient is the same
+    /// let far = perlin.gradient(p + Vec2::splat(25600.0));
+    /// assert!((far - perlin.gradient(p)).length() < 0.05 * far.length());
+    /// ```
+    fn gradient(&self, p: Vec2) -> Vec2 {
+        // far from the origin, coordinates are too coarse for a small step, so grow it with them
+        let step = |c: f32| 1e-3f32.max(c.abs() * f32::EPSILON * 16.0);
+        let (x0, x1) = (p.x - step(p.x), p.x + step(p.x));
+        let (y0, y1) = (p.y - step(p.y), p.y + step(p.y));
+        // divided by the step actually taken, which rounding may have changed
+        Vec2::new(
+            (self.noise(Vec2::new(x1, p.y)) - self.noise(Vec2::new(x0, p.y))) / (x1 - x0),
+            (self.noise(Vec2::new(p.x, y1)) - self.noise(Vec2::new(p.x, y0))) / (y1 - y0),
+        )
+    }
+
+    /// Returns the curl of the noise at `p`: the noise's [gradient](Noise::gradient) turned a quarter,
+    /// so the velocity flows along its contour lines.
+    ///
+    /// The resulting field is divergence-free (it never bunches up or thins out particles), which suits smoke and particle advection.
+    /// ```
+    /// # use vecto::{Vec2, noise::{Noise, Simplex}};
+    /// let wind = Simplex::new(5);
+    /// let p = Vec2::new(2.1, 0.6);
+    /// // flows across the slope, not up or down it
+    /// assert!(wind.curl_noise(p).dot(&wind.gradient(p)).abs() < 1e-4);
+    /// // divergence (∂vx/∂x + ∂vy/∂y) is zero
+    /// let h = 0.01;
+    /// let div = (wind.curl_noise(p + Vec2::new(h, 0.0)).x - wind.curl_noise(p - Vec2::new(h, 0.0)).x
+    ///     + wind.curl_noise(p + Vec2::new(0.0, h)).y - wind.curl_noise(p - Vec2::new(0.0, h)).y)
+    ///     / (2.0 * h);
+    /// assert!(div.abs() < 0.05);
+    /// ```
+    fn curl_noise(&self, p: Vec2) -> Vec2 {
+        let gradient = self.gradient(p);
+        Vec2::new(gradient.y, -gradient.x)
+    }
+}
+
+/// The gradients both noises pick from.
+const GRADIENTS: [Vec2; 8] = [
+    Vec2::new(1.0, 1.0),
+    Vec2::new(-1.0, 1.0),
+    Vec2::new(1.0, -1.0),
+    Vec2::new(-1.0, -1.0),
+    Vec2::new(1.0, 0.0),
+    Vec2::new(-1.0, 0.0),
+    Vec2::new(0.0, 1.0),
+    Vec2::new(0.0, -1.0),
+];
+
+/// A shuffled permutation of `0..256`, repeated once so lookups don't need to wrap.
+#[derive(Clone)]
+struct Permutation([u8; 512]);
+
+impl Permutation {
+    fn new(seed: u64) -> Self {
+        let mut state = seed;
+        // splitmix64
+        let mut next = || {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        };
+        let mut p = [0; 512];
+        #[allow(clippy::cast_possible_truncation)]
+        for (i, v) in p[..256].iter_mut().enumerate() {
+            *v = i as u8;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        for i in (1..256).rev() {
+            p.swap(i, (next() % (i as u64 + 1)) as usize);
+        }
+        p.copy_within(..256, 256);
+        Self(p)
+    }
+
+    /// Returns the gradient of lattice point `(x, y)`.
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    fn gradient(&self, x: i32, y: i32) -> Vec2 {
+        let (x, y) = ((x & 255) as usize, (y & 255) as usize);
+        GRADIENTS[usize::from(self.0[x + usize::from(self.0[y])]) % GRADIENTS.len()]
+    }
+}
+
+impl std::fmt::Debug for Permutation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Permutation")
+    }
+}
+
+/// Classic [Perlin noise](https://en.wikipedia.org/wiki/Perlin_noise), with features one unit apart.
+///
+/// Zero at every integer point.
+/// ```
+/// # use vecto::{Vec2, noise::{Noise, Perlin}};
+/// let perlin = Perlin::new(1);
+/// assert_eq!(perlin.noise(Vec2::new(3.0, -2.0)), 0.0);
+/// assert_ne!(perlin.noise(Vec2::new(3.5, -2.5)), Perlin::new(2).noise(Vec2::new(3.5, -2.5)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Perlin {
+    permutation: Permutation,
+}
+
+impl Perlin {
+    /// Construct a new [`Perlin`] noise. Equal seeds give equal noise.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            permutation: Permutation::new(seed),
+        }
+    }
+}
+
+impl Noise for Perlin {
+    #[allow(clippy::cast_possible_truncation)]
+    fn noise(&self, p: Vec2) -> f32 {
+        let cell = p.floor();
+        let (x, y) = (cell.x as i32, cell.y as i32);
+        let f = p - cell;
+        let corner = |dx: i32, dy: i32| {
+            #[allow(clippy::cast_precision_loss)]
+            let offset = Vec2::new(dx as f32, dy as f32);
+            self.permutation
+                .gradient(x.wrapping_add(dx), y.wrapping_add(dy))
+                .dot(&(f - offset))
+        };
+        // quintic fade, for continuous second derivatives
+        let fade = f.map(|t| t * t * t * (t * (t * 6.0 - 15.0) + 10.0));
+        let top = corner(0, 0) + (corner(1, 0) - corner(0, 0)) * fade.x;
+        let bottom = corner(0, 1) + (corner(1, 1) - corner(0, 1)) * fade.x;
+        top + (bottom - top) * fade.y
+    }
+}
+
+/// [Simplex noise](https://en.wikipedia.org/wiki/Simplex_noise): like [`Perlin`], but cheaper and without its axis-aligned artifacts.
+/// ```
+/// # use vecto::{Vec2, noise::{Noise, Simplex}};
+/// let simplex = Simplex::new(1);
+/// let v = simplex.noise(Vec2::new(0.3, 0.7));
+/// assert!((-1.0..=1.0).contains(&v));
+/// assert_eq!(v, Simplex::new(1).noise(Vec2::new(0.3, 0.7)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Simplex {
+    permutation: Permutation,
+}
+
+impl Simplex {
+    /// Construct a new [`Simplex`] noise. Equal seeds give equal noise.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            permutation: Permutation::new(seed),
+        }
+    }
+}
+
+impl Noise for Simplex {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn noise(&self, p: Vec2) -> f32 {
+        // skews the triangular grid onto the square grid, and back
+        let skew = 0.5 * (3.0f32.sqrt() - 1.0);
+        let unskew = (3.0 - 3.0f32.sqrt()) / 6.0;
+        let cell = (p + Vec2::splat(p.element_sum() * skew)).floor();
+        let (x, y) = (cell.x as i32, cell.y as i32);
+        let f0 = p - (cell - Vec2::splat(cell.element_sum() * unskew));
+        // which of the cell's two triangles `p` is in
+        let (dx, dy) = if f0.x > f0.y { (1, 0) } else { (0, 1) };
+        let f1 = f0 - Vec2::new(dx as f32, dy as f32) + Vec2::splat(unskew);
+        let f2 = f0 - Vec2::splat(1.0 - 2.0 * unskew);
+        let corner = |f: Vec2, dx: i32, dy: i32| {
+            let falloff = 0.5 - f.length_squared();
+            if falloff <= 0.0 {
+                return 0.0;
+            }
+            let gradient = self
+                .permutation
+                .gradient(x.wrapping_add(dx), y.wrapping_add(dy));
+            falloff.powi(4) * gradient.dot(&f)
+        };
+        (70.0 * (corner(f0, 0, 0) + corner(f1, dx, dy) + corner(f2, 1, 1))).clamp(-1.0, 1.0)
+    }
+}
+
+/// Fractional Brownian motion: sums `octaves` layers of noise, each at `lacunarity` times the frequency
+/// and `gain` times the amplitude of the last, for detail at every scale.
+#[derive(Clone, Debug)]
+pub struct Fbm<N> {
+    /// The noise to stack.
+    pub noise: N,
+    /// The number of layers.
+    pub octaves: u32,
+    /// The frequency multiplier between layers.
+    pub lacunarity: f32,
+    /// The amplitude multiplier between layers.
+    pub gain: f32,
+}
+
+impl<N: Noise> Fbm<N> {
+    /// Construct a new [`Fbm`], doubling the frequency and halving the amplitude every octave.
+    #[must_use]
+    pub fn new(noise: N, octaves: u32) -> Self {
+        Self {
+            noise,
+            octaves,
+            lacunarity: 2.0,
+            gain: 0.5,
+        }
+    }
+}
+
+impl<N: Noise> Noise for Fbm<N> {
+    /// Returns the layers' sum, divided by the sum of their amplitudes to stay in roughly `-1..=1`.
+    fn noise(&self, p: Vec2) -> f32 {
+        let (mut sum, mut total, mut amplitude, mut frequency) = (0.0, 0.0, 1.0, 1.0);
+        for _ in 0..self.octaves {
+            sum += self.noise.noise(p * frequency) * amplitude;
+            total += amplitude;
+            amplitude *= self.gain;
+            frequency *= self.lacunarity;
+        }
+        if total > 0.0 {
+            sum / total
+        } else {
+            0.0
+        }
+    }
+}
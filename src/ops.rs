@@ -1,4 +1,5 @@
 use crate::Vector2;
+use core::iter::{empty, Product, Sum};
 use core::ops::{
     Add as add, AddAssign as add_assign, Div as div, DivAssign as div_assign, Mul as mul,
     MulAssign as mul_assign, Neg, Rem as rem, RemAssign as rem_assign, Sub as sub,
@@ -10,6 +11,7 @@ macro_rules! op {
         impl<T: $name<T, Output = T>> $name<Vector2<T>> for Vector2<T> {
             type Output = Vector2<T>;
 
+            #[inline]
             fn $name(self, rhs: Vector2<T>) -> Self::Output {
                 Self::new(self.x.$name(rhs.x), self.y.$name(rhs.y))
             }
@@ -18,6 +20,7 @@ macro_rules! op {
         impl<T: Copy + $name<T, Output = T>> $name<&Vector2<T>> for Vector2<T> {
             type Output = Vector2<T>;
 
+            #[inline]
             fn $name(self, rhs: &Vector2<T>) -> Self::Output {
                 Self::new(self.x.$name(rhs.x), self.y.$name(rhs.y))
             }
@@ -25,6 +28,7 @@ macro_rules! op {
 
         impl<T: Copy + $name<T, Output = T>> $name<T> for Vector2<T> {
             type Output = Vector2<T>;
+            #[inline]
             fn $name(self, rhs: T) -> Self::Output {
                 Self::new(self.x.$name(rhs), self.y.$name(rhs))
             }
@@ -32,10 +36,63 @@ macro_rules! op {
 
         impl<T: Copy + $name<T, Output = T>> $name<&T> for Vector2<T> {
             type Output = Vector2<T>;
+            #[inline]
             fn $name(self, rhs: &T) -> Self::Output {
                 Self::new(self.x.$name(*rhs), self.y.$name(*rhs))
             }
         }
+
+        impl<T: $name<T, Output = T>> $name<(T, T)> for Vector2<T> {
+            type Output = Vector2<T>;
+
+            #[inline]
+            fn $name(self, rhs: (T, T)) -> Self::Output {
+                self.$name(Vector2::from(rhs))
+            }
+        }
+
+        impl<T: $name<T, Output = T>> $name<[T; 2]> for Vector2<T> {
+            type Output = Vector2<T>;
+
+            #[inline]
+            fn $name(self, rhs: [T; 2]) -> Self::Output {
+                self.$name(Vector2::from(rhs))
+            }
+        }
+
+        impl<T: Copy + $name<T, Output = T>> $name<Vector2<T>> for &Vector2<T> {
+            type Output = Vector2<T>;
+
+            #[inline]
+            fn $name(self, rhs: Vector2<T>) -> Self::Output {
+                (*self).$name(rhs)
+            }
+        }
+
+        impl<T: Copy + $name<T, Output = T>> $name<&Vector2<T>> for &Vector2<T> {
+            type Output = Vector2<T>;
+
+            #[inline]
+            fn $name(self, rhs: &Vector2<T>) -> Self::Output {
+                (*self).$name(*rhs)
+            }
+        }
+
+        impl<T: Copy + $name<T, Output = T>> $name<T> for &Vector2<T> {
+            type Output = Vector2<T>;
+            #[inline]
+            fn $name(self, rhs: T) -> Self::Output {
+                (*self).$name(rhs)
+            }
+        }
+
+        impl<T: Copy + $name<T, Output = T>> $name<&T> for &Vector2<T> {
+            type Output = Vector2<T>;
+            #[inline]
+            fn $name(self, rhs: &T) -> Self::Output {
+                (*self).$name(*rhs)
+            }
+        }
     };
 }
 op!(add);
@@ -47,6 +104,7 @@ op!(sub);
 macro_rules! assign {
     ($name:ident, $op:ident) => {
         impl<T: $name<T>> $name<Vector2<T>> for Vector2<T> {
+            #[inline]
             fn $name(&mut self, rhs: Vector2<T>) {
                 self.x.$name(rhs.x);
                 self.y.$name(rhs.y);
@@ -54,13 +112,29 @@ macro_rules! assign {
         }
 
         impl<T: Copy + $name<T>> $name<&Vector2<T>> for Vector2<T> {
+            #[inline]
             fn $name(&mut self, rhs: &Vector2<T>) {
                 self.x.$name(rhs.x);
                 self.y.$name(rhs.y);
             }
         }
 
+        impl<T: $name<T>> $name<(T, T)> for Vector2<T> {
+            #[inline]
+            fn $name(&mut self, rhs: (T, T)) {
+                self.$name(Vector2::from(rhs));
+            }
+        }
+
+        impl<T: $name<T>> $name<[T; 2]> for Vector2<T> {
+            #[inline]
+            fn $name(&mut self, rhs: [T; 2]) {
+                self.$name(Vector2::from(rhs));
+            }
+        }
+
         impl<T: Copy + $name<T>> $name<T> for Vector2<T> {
+            #[inline]
             fn $name(&mut self, rhs: T) {
                 self.x.$name(rhs);
                 self.y.$name(rhs);
@@ -68,6 +142,7 @@ macro_rules! assign {
         }
 
         impl<T: Copy + $name<T>> $name<&T> for Vector2<T> {
+            #[inline]
             fn $name(&mut self, rhs: &T) {
                 self.x.$name(*rhs);
                 self.y.$name(*rhs);
@@ -84,7 +159,210 @@ assign!(sub_assign, sub);
 impl<T: Neg<Output = T>> Neg for Vector2<T> {
     type Output = Vector2<T>;
 
+    #[inline]
     fn neg(self) -> Self::Output {
         Self::new(-self.x, -self.y)
     }
 }
+
+/// ```
+/// # use vecto::Vec2;
+/// fn midpoint<'a, V: 'a>(a: &'a V, b: &'a V) -> V
+/// where
+///     &'a V: std::ops::Add<&'a V, Output = V>,
+///     V: std::ops::Div<f32, Output = V>,
+/// {
+///     (a + b) / 2.0
+/// }
+/// assert_eq!(midpoint(&Vec2::ZERO, &Vec2::new(2.0, 4.0)), Vec2::new(1.0, 2.0));
+/// assert_eq!(-&Vec2::RIGHT, Vec2::LEFT);
+/// ```
+impl<T: Copy + Neg<Output = T>> Neg for &Vector2<T> {
+    type Output = Vector2<T>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+macro_rules! fold {
+    ($(#[$doc:meta])* $trait:ident, $fn:ident, $op:ident) => {
+        $(#[$doc])*
+        impl<T: $trait + $op<T, Output = T>> $trait for Vector2<T> {
+            #[inline]
+            fn $fn<I: Iterator<Item = Self>>(iter: I) -> Self {
+                let identity = Self::new(empty::<T>().$fn(), empty::<T>().$fn());
+                iter.fold(identity, $op::$op)
+            }
+        }
+
+        impl<'a, T: Copy + $trait + $op<T, Output = T>> $trait<&'a Vector2<T>> for Vector2<T> {
+            #[inline]
+            fn $fn<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().$fn()
+            }
+        }
+    };
+}
+fold!(
+    /// Adds up the vectors component-wise, giving zero for none.
+    /// ```
+    /// # use vecto::Vec2;
+    /// let points = vec![Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)];
+    /// assert_eq!(points.iter().sum::<Vec2>(), Vec2::new(4.0, 6.0));
+    /// assert_eq!(points.into_iter().product::<Vec2>(), Vec2::new(3.0, 8.0));
+    /// ```
+    Sum, sum, add
+);
+fold!(
+    /// Multiplies the vectors component-wise, giving one for none.
+    Product, product, mul
+);
+
+macro_rules! euclid {
+    ($($t:ty)+) => {$(
+        impl Vector2<$t> {
+            /// Component-wise euclidean division, rounding towards negative infinity for positive divisors.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("let v = Vector2::<", stringify!($t), ">::new(7 as _, 40 as _);")]
+            #[doc = concat!("assert_eq!(v.div_euclid(Vector2::new(2 as _, 16 as _)), Vector2::new(3 as _, 2 as _));")]
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn div_euclid(self, rhs: Self) -> Self {
+                self.zip(rhs, <$t>::div_euclid)
+            }
+
+            /// Component-wise euclidean division by the scalar `rhs`.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("let v = Vector2::<", stringify!($t), ">::new(7 as _, 40 as _);")]
+            #[doc = concat!("assert_eq!(v.div_euclid_scalar(16 as _), Vector2::new(0 as _, 2 as _));")]
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn div_euclid_scalar(self, rhs: $t) -> Self {
+                self.map(|c| c.div_euclid(rhs))
+            }
+
+            /// Component-wise euclidean remainder, never negative, unlike `%`.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("let v = Vector2::<", stringify!($t), ">::new(7 as _, 40 as _);")]
+            #[doc = concat!("assert_eq!(v.rem_euclid(Vector2::new(2 as _, 16 as _)), Vector2::new(1 as _, 8 as _));")]
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn rem_euclid(self, rhs: Self) -> Self {
+                self.zip(rhs, <$t>::rem_euclid)
+            }
+
+            /// Component-wise euclidean remainder of division by the scalar `rhs`.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("let v = Vector2::<", stringify!($t), ">::new(7 as _, 40 as _);")]
+            #[doc = concat!("assert_eq!(v.rem_euclid_scalar(16 as _), Vector2::new(7 as _, 8 as _));")]
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub fn rem_euclid_scalar(self, rhs: $t) -> Self {
+                self.map(|c| c.rem_euclid(rhs))
+            }
+        }
+    )+};
+}
+euclid!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+macro_rules! konst {
+    ($($t:ty)+) => {$(
+        impl Vector2<$t> {
+            /// `const` version of `self + rhs`.
+            /// ```
+            /// # use vecto::Vector2;
+            #[doc = concat!("const ORIGIN: Vector2<", stringify!($t), "> = Vector2::new(1 as _, 2 as _);")]
+            #[doc = concat!("const OFFSETS: [Vector2<", stringify!($t), ">; 2] = [")]
+            ///     ORIGIN.const_add(Vector2::new(1 as _, 0 as _)),
+            ///     ORIGIN.const_mul(Vector2::splat(2 as _)),
+            /// ];
+            /// assert_eq!(OFFSETS, [Vector2::new(2 as _, 2 as _), Vector2::new(2 as _, 4 as _)]);
+            /// ```
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub const fn const_add(self, rhs: Self) -> Self {
+                Self::new(self.x + rhs.x, self.y + rhs.y)
+            }
+
+            /// `const` version of `self - rhs`.
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub const fn const_sub(self, rhs: Self) -> Self {
+                Self::new(self.x - rhs.x, self.y - rhs.y)
+            }
+
+            /// `const` version of `self * rhs`.
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub const fn const_mul(self, rhs: Self) -> Self {
+                Self::new(self.x * rhs.x, self.y * rhs.y)
+            }
+
+            /// `const` version of `self / rhs`.
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub const fn const_div(self, rhs: Self) -> Self {
+                Self::new(self.x / rhs.x, self.y / rhs.y)
+            }
+
+            /// `const` version of [`Vector2::dot`].
+            #[must_use]
+            #[inline]
+            pub const fn const_dot(self, with: Self) -> $t {
+                self.x * with.x + self.y * with.y
+            }
+
+            /// `const` version of [`Vector2::length_squared`].
+            #[must_use]
+            #[inline]
+            pub const fn const_length_squared(self) -> $t {
+                self.const_dot(self)
+            }
+
+            /// Returns the component-wise minimum of `self` and `with`, in a `const` context.
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub const fn const_min(self, with: Self) -> Self {
+                Self::new(
+                    if self.x < with.x { self.x } else { with.x },
+                    if self.y < with.y { self.y } else { with.y },
+                )
+            }
+
+            /// Returns the component-wise maximum of `self` and `with`, in a `const` context.
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub const fn const_max(self, with: Self) -> Self {
+                Self::new(
+                    if self.x > with.x { self.x } else { with.x },
+                    if self.y > with.y { self.y } else { with.y },
+                )
+            }
+        }
+    )+};
+}
+konst!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+macro_rules! konst_neg {
+    ($($t:ty)+) => {$(
+        impl Vector2<$t> {
+            /// `const` version of `-self`.
+            #[must_use = "Does not modify in place."]
+            #[inline]
+            pub const fn const_neg(self) -> Self {
+                Self::new(-self.x, -self.y)
+            }
+        }
+    )+};
+}
+konst_neg!(i8 i16 i32 i64 i128 isize f32 f64);
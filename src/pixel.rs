@@ -0,0 +1,157 @@
+//! Pixel grid helpers, for crisp pixel-art rendering, and conversions between pixel, texture and device coordinates.
+use crate::Vec2;
+
+/// How [`Vec2::snapped_to_pixels`] rounds positions exactly halfway between two pixels.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum PixelRounding {
+    /// Round halfway cases away from zero, like [`f32::round`]. Symmetric around the origin.
+    #[default]
+    HalfAwayFromZero,
+    /// Round halfway cases towards positive infinity. Consistent across the origin, so nothing jitters when crossing it.
+    HalfUp,
+    /// Round halfway cases to the even pixel, like [`f32::round_ties_even`].
+    HalfEven,
+}
+
+impl PixelRounding {
+    fn round(self, x: f32) -> f32 {
+        match self {
+            Self::HalfAwayFromZero => x.round(),
+            Self::HalfUp => (x + 0.5).floor(),
+            Self::HalfEven => x.round_ties_even(),
+        }
+    }
+}
+
+/// Which way Y points in normalized device coordinates.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum NdcY {
+    /// Y is up, with -1 at the bottom of the viewport: OpenGL, Direct3D, Metal and WebGPU.
+    #[default]
+    Up,
+    /// Y is down, with -1 at the top of the viewport: Vulkan.
+    Down,
+}
+
+impl Vec2 {
+    /// Snaps a world position to the nearest pixel corner, given how many pixels make up one world unit.
+    /// ```
+    /// # use vecto::{Vec2, pixel::PixelRounding};
+    /// let p = Vec2::new(1.23, -0.5625);
+    /// assert_eq!(p.snapped_to_pixels(16.0, PixelRounding::HalfAwayFromZero), Vec2::new(1.25, -0.5625));
+    /// assert_eq!(Vec2::splat(-0.5).snapped_to_pixels(1.0, PixelRounding::HalfAwayFromZero), Vec2::splat(-1.0));
+    /// assert_eq!(Vec2::splat(-0.5).snapped_to_pixels(1.0, PixelRounding::HalfUp), Vec2::splat(0.0));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn snapped_to_pixels(self, pixels_per_unit: f32, rounding: PixelRounding) -> Self {
+        let p = self * pixels_per_unit;
+        Vec2::new(rounding.round(p.x), rounding.round(p.y)) / pixels_per_unit
+    }
+
+    /// Snaps a world position to the center of the pixel containing it, given how many pixels make up one world unit.
+    /// ```
+    /// # use vecto::Vec2;
+    /// assert_eq!(Vec2::new(1.9, -0.1).snapped_to_pixel_centers(1.0), Vec2::new(1.5, -0.5));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn snapped_to_pixel_centers(self, pixels_per_unit: f32) -> Self {
+        ((self * pixels_per_unit).floor() + 0.5) / pixels_per_unit
+    }
+
+    /// Returns the offset of half a texel in UV space, for a texture of `texture_size` pixels.
+    /// Adding it to a texel's corner UV samples that texel's center.
+    /// ```
+    /// # use vecto::Vec2;
+    /// assert_eq!(Vec2::half_texel(Vec2::new(256.0, 128.0)), Vec2::new(1.0 / 512.0, 1.0 / 256.0));
+    /// ```
+    #[must_use]
+    pub fn half_texel(texture_size: Vec2) -> Self {
+        Vec2::splat(0.5) / texture_size
+    }
+
+    /// Converts pixel coordinates to UVs, where `(0, 0)` is the top-left corner of the texture and `(1, 1)` the bottom-right.
+    /// ```
+    /// # use vecto::Vec2;
+    /// let sheet = Vec2::new(256.0, 128.0);
+    /// assert_eq!(Vec2::new(64.0, 32.0).to_uv(sheet), Vec2::splat(0.25));
+    /// assert_eq!(Vec2::from_uv(Vec2::splat(0.25), sheet), Vec2::new(64.0, 32.0));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn to_uv(self, texture_size: Vec2) -> Self {
+        self / texture_size
+    }
+
+    /// Converts UVs to pixel coordinates, undoing [`Vec2::to_uv`].
+    #[must_use]
+    pub fn from_uv(uv: Vec2, texture_size: Vec2) -> Self {
+        uv * texture_size
+    }
+
+    /// Converts a texel's coordinates to the UV of its center, which samples it without bleeding into its neighbours.
+    /// ```
+    /// # use vecto::Vec2;
+    /// let sheet = Vec2::new(4.0, 2.0);
+    /// assert_eq!(Vec2::new(0.0, 1.0).to_uv_centered(sheet), Vec2::new(0.125, 0.75));
+    /// assert_eq!(Vec2::from_uv_centered(Vec2::new(0.125, 0.75), sheet), Vec2::new(0.0, 1.0));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn to_uv_centered(self, texture_size: Vec2) -> Self {
+        (self + 0.5) / texture_size
+    }
+
+    /// Converts a UV to texel coordinates, with texel centers on whole numbers, undoing [`Vec2::to_uv_centered`].
+    #[must_use]
+    pub fn from_uv_centered(uv: Vec2, texture_size: Vec2) -> Self {
+        uv * texture_size - 0.5
+    }
+
+    /// Converts viewport coordinates, `(0, 0)` at the top-left and `(1, 1)` at the bottom-right,
+    /// to normalized device coordinates, `-1..=1` on both axes.
+    ///
+    /// Viewport coordinates are pixel coordinates over the viewport size, see [`Vec2::to_uv`].
+    /// ```
+    /// # use vecto::{Vec2, pixel::NdcY};
+    /// assert_eq!(Vec2::new(0.0, 0.0).viewport_to_ndc(NdcY::Up), Vec2::new(-1.0, 1.0));
+    /// assert_eq!(Vec2::new(0.0, 0.0).viewport_to_ndc(NdcY::Down), Vec2::new(-1.0, -1.0));
+    /// assert_eq!(Vec2::new(0.5, 0.75).viewport_to_ndc(NdcY::Up), Vec2::new(0.0, -0.5));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn viewport_to_ndc(self, y: NdcY) -> Self {
+        let ndc = self * 2.0 - 1.0;
+        match y {
+            NdcY::Up => Vec2::new(ndc.x, -ndc.y),
+            NdcY::Down => ndc,
+        }
+    }
+
+    /// Converts normalized device coordinates to viewport coordinates, undoing [`Vec2::viewport_to_ndc`].
+    #[must_use = "Does not modify in place."]
+    pub fn ndc_to_viewport(self, y: NdcY) -> Self {
+        let ndc = match y {
+            NdcY::Up => Vec2::new(self.x, -self.y),
+            NdcY::Down => self,
+        };
+        (ndc + 1.0) / 2.0
+    }
+
+    /// Converts pixel coordinates in a viewport of `viewport_size` pixels to normalized device coordinates,
+    /// such as for picking with the mouse.
+    /// ```
+    /// # use vecto::{Vec2, pixel::NdcY};
+    /// let window = Vec2::new(800.0, 600.0);
+    /// let mouse = Vec2::new(600.0, 150.0);
+    /// assert_eq!(mouse.pixel_to_ndc(window, NdcY::Up), Vec2::new(0.5, 0.5));
+    /// assert_eq!(Vec2::new(0.5, 0.5).ndc_to_pixel(window, NdcY::Up), mouse);
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn pixel_to_ndc(self, viewport_size: Vec2, y: NdcY) -> Self {
+        self.to_uv(viewport_size).viewport_to_ndc(y)
+    }
+
+    /// Converts normalized device coordinates to pixel coordinates in a viewport of `viewport_size` pixels,
+    /// undoing [`Vec2::pixel_to_ndc`].
+    #[must_use = "Does not modify in place."]
+    pub fn ndc_to_pixel(self, viewport_size: Vec2, y: NdcY) -> Self {
+        Vec2::from_uv(self.ndc_to_viewport(y), viewport_size)
+    }
+}
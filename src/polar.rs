@@ -0,0 +1,111 @@
+use crate::{angle::wrap_angle, Vec2};
+use core::ops::{Div, DivAssign, Mul, MulAssign};
+use std::f32::consts::PI;
+
+/// A point in polar coordinates: a distance from the origin and an angle from the positive X axis.
+/// ```
+/// # use vecto::{Kinda, Polar, Vec2};
+/// # use std::f32::consts::PI;
+/// let p = Vec2::DOWN.to_polar();
+/// assert_eq!(p, Polar::new(1.0, PI / 2.0));
+/// assert!((p * 2.0).rotated(PI / 2.0).to_vec2().approx_eq(Vec2::new(-2.0, 0.0)));
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Default, Debug)]
+pub struct Polar {
+    /// The distance from the origin.
+    pub r: f32,
+    /// The angle from the positive X axis, in radians.
+    pub theta: f32,
+}
+
+impl Polar {
+    /// Construct a new [`Polar`].
+    #[must_use]
+    pub const fn new(r: f32, theta: f32) -> Self {
+        Self { r, theta }
+    }
+
+    /// Converts back to cartesian coordinates.
+    #[must_use]
+    pub fn to_vec2(self) -> Vec2 {
+        Vec2::from_angle(self.theta) * self.r
+    }
+
+    /// Returns the same point rotated by `angle` radians around the origin.
+    #[must_use = "Does not modify in place."]
+    pub fn rotated(self, angle: f32) -> Self {
+        Self::new(self.r, self.theta + angle)
+    }
+
+    /// Returns the canonical form of the same point: `r` not negative and `theta` in `(-π, π]` (and zero if `r` is).
+    /// ```
+    /// # use vecto::{Kinda, Polar};
+    /// # use std::f32::consts::PI;
+    /// assert_eq!(Polar::new(-1.0, 0.0).normalized(), Polar::new(1.0, PI));
+    /// assert!(Polar::new(2.0, 3.5 * PI).normalized().theta.approx_eq(-PI / 2.0));
+    /// assert_eq!(Polar::new(0.0, 3.0).normalized(), Polar::new(0.0, 0.0));
+    /// assert_eq!(Polar::new(1.0, -PI).normalized(), Polar::new(1.0, PI));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn normalized(self) -> Self {
+        if self.r == 0.0 {
+            return Self::new(0.0, 0.0);
+        }
+        let (r, theta) = if self.r < 0.0 {
+            (-self.r, self.theta + PI)
+        } else {
+            (self.r, self.theta)
+        };
+        Self::new(r, wrap_angle(theta))
+    }
+}
+
+impl Vec2 {
+    /// Converts to polar coordinates, with `theta` in `-π..=π`.
+    #[must_use]
+    pub fn to_polar(self) -> Polar {
+        Polar::new(self.length(), self.angle())
+    }
+}
+
+impl From<Vec2> for Polar {
+    fn from(value: Vec2) -> Self {
+        value.to_polar()
+    }
+}
+
+impl From<Polar> for Vec2 {
+    fn from(value: Polar) -> Self {
+        value.to_vec2()
+    }
+}
+
+impl Mul<f32> for Polar {
+    type Output = Polar;
+
+    /// Scales the distance from the origin.
+    fn mul(self, rhs: f32) -> Polar {
+        Self::new(self.r * rhs, self.theta)
+    }
+}
+
+impl MulAssign<f32> for Polar {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.r *= rhs;
+    }
+}
+
+impl Div<f32> for Polar {
+    type Output = Polar;
+
+    /// Scales the distance from the origin.
+    fn div(self, rhs: f32) -> Polar {
+        Self::new(self.r / rhs, self.theta)
+    }
+}
+
+impl DivAssign<f32> for Polar {
+    fn div_assign(&mut self, rhs: f32) {
+        self.r /= rhs;
+    }
+}
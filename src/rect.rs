@@ -0,0 +1,358 @@
+use crate::{IVec2, Vec2, Vector2};
+use core::ops::Add;
+
+/// How [`Rect2::fit_into`] scales one rectangle into another, keeping its aspect ratio.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum ScaleMode {
+    /// As large as fits entirely inside, leaving bars along two sides (letterboxing).
+    #[default]
+    Contain,
+    /// As small as covers the whole container, cropping the overflow.
+    Cover,
+}
+
+/// Where in a [`Rect2`] something is placed by [`Rect2::place`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Anchor {
+    /// The top-left corner.
+    #[default]
+    TopLeft,
+    /// The middle of the top edge.
+    Top,
+    /// The top-right corner.
+    TopRight,
+    /// The middle of the left edge.
+    Left,
+    /// The center.
+    Center,
+    /// The middle of the right edge.
+    Right,
+    /// The bottom-left corner.
+    BottomLeft,
+    /// The middle of the bottom edge.
+    Bottom,
+    /// The bottom-right corner.
+    BottomRight,
+}
+
+impl Anchor {
+    /// Returns the anchor's position in the unit square, from `(0, 0)` at the top-left to `(1, 1)` at the bottom-right.
+    /// ```
+    /// # use vecto::{Anchor, Vec2};
+    /// assert_eq!(Anchor::BottomLeft.factor(), Vec2::new(0.0, 1.0));
+    /// ```
+    #[must_use]
+    pub const fn factor(self) -> Vec2 {
+        match self {
+            Self::TopLeft => Vec2::new(0.0, 0.0),
+            Self::Top => Vec2::new(0.5, 0.0),
+            Self::TopRight => Vec2::new(1.0, 0.0),
+            Self::Left => Vec2::new(0.0, 0.5),
+            Self::Center => Vec2::new(0.5, 0.5),
+            Self::Right => Vec2::new(1.0, 0.5),
+            Self::BottomLeft => Vec2::new(0.0, 1.0),
+            Self::Bottom => Vec2::new(0.5, 1.0),
+            Self::BottomRight => Vec2::new(1.0, 1.0),
+        }
+    }
+}
+
+/// The widths of the borders of a [`Rect2::nine_slice`].
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Margins {
+    /// The left border.
+    pub left: f32,
+    /// The top border.
+    pub top: f32,
+    /// The right border.
+    pub right: f32,
+    /// The bottom border.
+    pub bottom: f32,
+}
+
+impl Margins {
+    /// Construct new [`Margins`].
+    #[must_use]
+    pub const fn new(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// Construct new [`Margins`], the same on every side.
+    #[must_use]
+    pub const fn splat(margin: f32) -> Self {
+        Self::new(margin, margin, margin, margin)
+    }
+}
+
+/// Axis-aligned rectangle, as a position (top-left corner) and a size.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Default, Hash, Eq, Ord, Debug)]
+pub struct Rect2<T> {
+    /// The top-left corner.
+    pub position: Vector2<T>,
+    /// The width and height.
+    pub size: Vector2<T>,
+}
+
+impl<T> Rect2<T> {
+    /// Construct a new [`Rect2`].
+    #[must_use]
+    pub const fn new(position: Vector2<T>, size: Vector2<T>) -> Self {
+        Self { position, size }
+    }
+}
+
+impl<T: Copy + Add<T, Output = T>> Rect2<T> {
+    /// Returns the bottom-right corner, `position + size`.
+    #[must_use]
+    pub fn end(&self) -> Vector2<T> {
+        self.position + self.size
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<T, Output = T>> Rect2<T> {
+    /// Returns the point inside (or on the edge of) the rectangle nearest to `point`. The size must not be negative.
+    /// ```
+    /// # use vecto::{IVec2, Rect2, Vec2};
+    /// let r = Rect2::new(Vec2::ZERO, Vec2::new(4.0, 2.0));
+    /// assert_eq!(r.clamp_point(Vec2::new(5.0, 1.0)), Vec2::new(4.0, 1.0));
+    /// assert_eq!(r.clamp_point(Vec2::new(1.0, 1.0)), Vec2::new(1.0, 1.0));
+    /// let r = Rect2::new(IVec2::new(0, 0), IVec2::new(4, 2));
+    /// assert_eq!(r.clamp_point(IVec2::new(-3, 9)), IVec2::new(0, 2));
+    /// ```
+    #[must_use]
+    pub fn clamp_point(&self, point: Vector2<T>) -> Vector2<T> {
+        let clamp = |v: T, lo: T, hi: T| {
+            if v < lo {
+                lo
+            } else if v > hi {
+                hi
+            } else {
+                v
+            }
+        };
+        let end = self.end();
+        Vector2::new(
+            clamp(point.x, self.position.x, end.x),
+            clamp(point.y, self.position.y, end.y),
+        )
+    }
+}
+
+impl Rect2<f32> {
+    /// Returns the center of the rectangle.
+    #[must_use]
+    pub fn center(&self) -> Vec2 {
+        self.position + self.size / 2.0
+    }
+
+    /// Returns the area (`width * height`) of the rectangle.
+    #[must_use]
+    pub fn area(&self) -> f32 {
+        self.size.x * self.size.y
+    }
+
+    /// Maps `uv`, in the unit square, onto the rectangle: `(0, 0)` is the top-left corner and `(1, 1)` the bottom-right.
+    /// ```
+    /// # use vecto::{Rect2, Vec2};
+    /// let r = Rect2::new(Vec2::splat(2.0), Vec2::new(4.0, 2.0));
+    /// assert_eq!(r.point_at(Vec2::splat(0.5)), r.center());
+    /// ```
+    #[must_use]
+    pub fn point_at(&self, uv: Vec2) -> Vec2 {
+        self.position + self.size * uv
+    }
+
+    /// Returns true if `point` is inside the rectangle. The bottom and right edges are excluded.
+    /// ```
+    /// # use vecto::{Rect2, Vec2};
+    /// let r = Rect2::new(Vec2::ZERO, Vec2::splat(2.0));
+    /// assert!(r.contains_point(Vec2::splat(1.0)));
+    /// assert!(r.contains_point(Vec2::ZERO));
+    /// assert!(!r.contains_point(Vec2::splat(2.0)));
+    /// ```
+    #[must_use]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        let end = self.end();
+        point.x >= self.position.x
+            && point.y >= self.position.y
+            && point.x < end.x
+            && point.y < end.y
+    }
+
+    /// Returns a copy of the rectangle grown to include `point`.
+    /// ```
+    /// # use vecto::{Rect2, Vec2};
+    /// let r = Rect2::new(Vec2::ZERO, Vec2::splat(1.0)).expand(Vec2::new(-1.0, 3.0));
+    /// assert_eq!(r, Rect2::new(Vec2::new(-1.0, 0.0), Vec2::new(2.0, 3.0)));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn expand(self, point: Vec2) -> Self {
+        let begin = Vec2::new(self.position.x.min(point.x), self.position.y.min(point.y));
+        let end = self.end();
+        let end = Vec2::new(end.x.max(point.x), end.y.max(point.y));
+        Self::new(begin, end - begin)
+    }
+
+    /// Returns the smallest rectangle containing every point, or [`None`] if there are no points.
+    /// ```
+    /// # use vecto::{Rect2, Vec2};
+    /// let r = Rect2::from_points([Vec2::new(1.0, 4.0), Vec2::new(3.0, 2.0)]).unwrap();
+    /// assert_eq!(r, Rect2::new(Vec2::new(1.0, 2.0), Vec2::splat(2.0)));
+    /// ```
+    #[must_use]
+    pub fn from_points(points: impl IntoIterator<Item = Vec2>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, Vec2::ZERO), Self::expand))
+    }
+
+    /// Returns the top-left corner at which to place something of `size` at `anchor` in the rectangle,
+    /// kept `margin` away from the edges it is anchored to.
+    /// ```
+    /// # use vecto::{Anchor, Rect2, Vec2};
+    /// let screen = Rect2::new(Vec2::ZERO, Vec2::new(800.0, 600.0));
+    /// let button = Vec2::new(100.0, 40.0);
+    /// let margin = Vec2::splat(10.0);
+    /// assert_eq!(screen.place(button, Anchor::BottomRight, margin), Vec2::new(690.0, 550.0));
+    /// assert_eq!(screen.place(button, Anchor::Top, margin), Vec2::new(350.0, 10.0));
+    /// assert_eq!(screen.place(button, Anchor::Center, margin), Vec2::new(350.0, 280.0));
+    /// ```
+    #[must_use]
+    pub fn place(&self, size: Vec2, anchor: Anchor, margin: Vec2) -> Vec2 {
+        let inner = self.size - margin * 2.0;
+        self.position + margin + (inner - size) * anchor.factor()
+    }
+
+    /// Returns the uniform `(scale, offset)` that fits the rectangle into `container`, centered,
+    /// so that a point `p` of the rectangle lands on `p * scale + offset`.
+    /// ```
+    /// # use vecto::{Rect2, ScaleMode, Vec2};
+    /// let game = Rect2::new(Vec2::ZERO, Vec2::new(320.0, 180.0));
+    /// let window = Rect2::new(Vec2::ZERO, Vec2::new(800.0, 600.0));
+    /// // letterboxed, with bars above and below
+    /// assert_eq!(game.fit_into(window, ScaleMode::Contain), (2.5, Vec2::new(0.0, 75.0)));
+    /// // filling the window, cropping the sides
+    /// let (scale, offset) = game.fit_into(window, ScaleMode::Cover);
+    /// assert_eq!(scale, 600.0 / 180.0);
+    /// assert_eq!(game.center() * scale + offset, window.center());
+    /// ```
+    #[must_use]
+    pub fn fit_into(&self, container: Rect2<f32>, mode: ScaleMode) -> (f32, Vec2) {
+        let (x, y) = (
+            container.size.x / self.size.x,
+            container.size.y / self.size.y,
+        );
+        let scale = match mode {
+            ScaleMode::Contain => x.min(y),
+            ScaleMode::Cover => x.max(y),
+        };
+        (scale, container.center() - self.center() * scale)
+    }
+
+    /// Converts a rectangle in pixels to UVs, for a texture of `texture_size` pixels. See [`Vec2::to_uv`].
+    /// ```
+    /// # use vecto::{Rect2, Vec2};
+    /// let sprite = Rect2::new(Vec2::new(32.0, 0.0), Vec2::new(16.0, 16.0));
+    /// let uv = sprite.to_uv(Vec2::new(64.0, 32.0));
+    /// assert_eq!(uv, Rect2::new(Vec2::new(0.5, 0.0), Vec2::new(0.25, 0.5)));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn to_uv(self, texture_size: Vec2) -> Self {
+        Self::new(
+            self.position.to_uv(texture_size),
+            self.size.to_uv(texture_size),
+        )
+    }
+
+    /// Splits the rectangle into the nine parts of a scalable panel: four fixed corners, four edges that stretch along
+    /// one axis, and a center that stretches along both. They are in row-major order, matching the order of [`Anchor`],
+    /// so `slices[Anchor::Top as usize]` is the top edge.
+    ///
+    /// Margins too big to fit are shrunk in proportion, leaving an empty center.
+    /// ```
+    /// # use vecto::{Anchor, Margins, Rect2, Vec2};
+    /// let panel = Rect2::new(Vec2::ZERO, Vec2::new(100.0, 50.0));
+    /// let slices = panel.nine_slice(Margins::splat(8.0));
+    /// assert_eq!(slices[Anchor::TopLeft as usize], Rect2::new(Vec2::ZERO, Vec2::splat(8.0)));
+    /// assert_eq!(slices[Anchor::Center as usize], Rect2::new(Vec2::splat(8.0), Vec2::new(84.0, 34.0)));
+    /// assert_eq!(slices[Anchor::BottomRight as usize].end(), panel.end());
+    /// // the matching parts of a 24x24 sprite with the same borders, for texturing them
+    /// let sprite = Rect2::new(Vec2::new(24.0, 0.0), Vec2::splat(24.0));
+    /// let uvs = sprite.nine_slice(Margins::splat(8.0)).map(|r| r.to_uv(Vec2::new(48.0, 24.0)));
+    /// assert_eq!(uvs[Anchor::Center as usize], Rect2::new(Vec2::new(2.0 / 3.0, 1.0 / 3.0), Vec2::new(1.0 / 6.0, 1.0 / 3.0)));
+    /// ```
+    #[must_use]
+    pub fn nine_slice(&self, margins: Margins) -> [Self; 9] {
+        let fit = |start: f32, end: f32, size: f32| {
+            let total = start + end;
+            if total > size && total > 0.0 {
+                let shrink = size.max(0.0) / total;
+                (start * shrink, end * shrink)
+            } else {
+                (start, end)
+            }
+        };
+        let (left, right) = fit(margins.left, margins.right, self.size.x);
+        let (top, bottom) = fit(margins.top, margins.bottom, self.size.y);
+        let end = self.end();
+        let xs = [
+            self.position.x,
+            self.position.x + left,
+            end.x - right,
+            end.x,
+        ];
+        let ys = [
+            self.position.y,
+            self.position.y + top,
+            end.y - bottom,
+            end.y,
+        ];
+        core::array::from_fn(|i| {
+            let (column, row) = (i % 3, i / 3);
+            let position = Vec2::new(xs[column], ys[row]);
+            Self::new(position, Vec2::new(xs[column + 1], ys[row + 1]) - position)
+        })
+    }
+}
+
+impl Rect2<i32> {
+    /// Returns every point (cell) in the rectangle, in row-major order. The bottom and right edges are excluded.
+    /// ```
+    /// # use vecto::{IVec2, Rect2};
+    /// let r = Rect2::new(IVec2::new(1, 1), IVec2::new(2, 2));
+    /// let points: Vec<_> = r.iter_points().collect();
+    /// assert_eq!(points, [IVec2::new(1, 1), IVec2::new(2, 1), IVec2::new(1, 2), IVec2::new(2, 2)]);
+    /// ```
+    pub fn iter_points(&self) -> impl Iterator<Item = IVec2> + Clone {
+        let (begin, end) = (self.position, self.end());
+        (begin.y..end.y).flat_map(move |y| (begin.x..end.x).map(move |x| IVec2::new(x, y)))
+    }
+
+    /// Returns the points along the inside edge of the rectangle, in row-major order.
+    /// ```
+    /// # use vecto::{IVec2, Rect2};
+    /// let r = Rect2::new(IVec2::new(0, 0), IVec2::new(3, 3));
+    /// assert_eq!(r.iter_border().count(), 8);
+    /// assert!(!r.iter_border().any(|p| p == IVec2::new(1, 1)));
+    /// ```
+    pub fn iter_border(&self) -> impl Iterator<Item = IVec2> + Clone {
+        let (begin, end) = (self.position, self.end());
+        (begin.y..end.y).flat_map(move |y| {
+            let full = y == begin.y || y == end.y - 1;
+            // on inner rows, jump from the left edge straight to the right edge
+            let step = if full {
+                1
+            } else {
+                (end.x - begin.x - 1).max(1).unsigned_abs()
+            };
+            (begin.x..end.x)
+                .step_by(step as usize)
+                .map(move |x| IVec2::new(x, y))
+        })
+    }
+}
@@ -0,0 +1,168 @@
+//! Adaptive precision geometric predicates, after [Shewchuk](https://www.cs.cmu.edu/~quake/robust.html).
+//!
+//! These answer which side of a line, or circle, a point is on exactly, where plain floating point
+//! arithmetic can give the wrong sign for nearly collinear (or cocircular) points. They first try
+//! fast `f64` arithmetic, and only fall back to exact arithmetic when its error bound can't decide.
+use crate::Vec2;
+
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const ORIENT_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const INCIRCLE_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+/// Returns a positive value if `a`, `b` and `c` wind so that [`Vec2::cross`] is positive (clockwise on screen, as Y is down),
+/// negative if they wind the other way, and zero if they are collinear. The sign is always exact.
+///
+/// The magnitude is roughly twice the area of the triangle.
+/// ```
+/// # use vecto::{Vec2, robust::orient2d};
+/// assert!(orient2d(Vec2::ZERO, Vec2::RIGHT, Vec2::DOWN) > 0.0);
+/// assert_eq!(orient2d(Vec2::ZERO, Vec2::splat(1.0), Vec2::splat(1e30)), 0.0);
+/// // f32 arithmetic can't tell these apart from collinear
+/// let (a, b, c) = (Vec2::new(0.5, 0.50000006), Vec2::new(12.0, 12.0), Vec2::new(24.0, 24.0));
+/// assert_eq!((b - a).cross(&(c - a)), 0.0);
+/// assert!(orient2d(a, b, c) > 0.0);
+/// ```
+#[must_use]
+pub fn orient2d(a: Vec2, b: Vec2, c: Vec2) -> f64 {
+    let [a, b, c] = [a, b, c].map(|p| p.map(f64::from));
+    let left = (a.x - c.x) * (b.y - c.y);
+    let right = (a.y - c.y) * (b.x - c.x);
+    let det = left - right;
+    if det.abs() >= ORIENT_BOUND * (left.abs() + right.abs()) {
+        return det;
+    }
+    // the coordinates came from f32s, so these products are exact
+    let mut sum = vec![];
+    for term in [
+        a.x * b.y,
+        -a.y * b.x,
+        b.x * c.y,
+        -b.y * c.x,
+        c.x * a.y,
+        -c.y * a.x,
+    ] {
+        grow(&mut sum, term);
+    }
+    estimate(&sum)
+}
+
+/// Returns a positive value if `d` is inside the circle through `a`, `b` and `c`, negative if it is outside,
+/// and zero if it is on it. The sign is always exact.
+///
+/// `a`, `b` and `c` must wind so that [`orient2d`] is positive; otherwise the sign is flipped.
+/// ```
+/// # use vecto::{Vec2, robust::incircle};
+/// let (a, b, c) = (Vec2::new(-1.0, 0.0), Vec2::new(0.0, -1.0), Vec2::new(1.0, 0.0));
+/// assert!(incircle(a, b, c, Vec2::ZERO) > 0.0);
+/// assert!(incircle(a, b, c, Vec2::new(0.0, 2.0)) < 0.0);
+/// assert_eq!(incircle(a, b, c, Vec2::new(0.0, 1.0)), 0.0);
+/// // a circle of radius 2²⁰ through the origin, and a point just off it
+/// let r = 1048576.0;
+/// let (b, c, d) = (Vec2::new(2.0 * r, 0.0), Vec2::new(r, r), Vec2::new(r, -r));
+/// assert_eq!(incircle(Vec2::ZERO, b, c, d), 0.0);
+/// assert!(incircle(Vec2::new(2f32.powi(-40), 0.0), b, c, d) < 0.0);
+/// ```
+#[must_use]
+pub fn incircle(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> f64 {
+    let [a, b, c, d] = [a, b, c, d].map(|p| p.map(f64::from));
+    let [ad, bd, cd] = [a, b, c].map(|p| p - d);
+    let (bc, cb) = (bd.x * cd.y, cd.x * bd.y);
+    let (ca, ac) = (cd.x * ad.y, ad.x * cd.y);
+    let (ab, ba) = (ad.x * bd.y, bd.x * ad.y);
+    let lifts = [ad, bd, cd].map(|p| p.length_squared());
+    let det = lifts[0] * (bc - cb) + lifts[1] * (ca - ac) + lifts[2] * (ab - ba);
+    let permanent = (bc.abs() + cb.abs()) * lifts[0]
+        + (ca.abs() + ac.abs()) * lifts[1]
+        + (ab.abs() + ba.abs()) * lifts[2];
+    if det.abs() > INCIRCLE_BOUND * permanent {
+        return det;
+    }
+    exact_incircle(a, b, c, d)
+}
+
+/// [`incircle`] in exact arithmetic, with every intermediate value an expansion.
+fn exact_incircle(
+    a: crate::Vector2<f64>,
+    b: crate::Vector2<f64>,
+    c: crate::Vector2<f64>,
+    d: crate::Vector2<f64>,
+) -> f64 {
+    let difference = |x: f64, y: f64| {
+        let (sum, error) = two_sum(x, -y);
+        vec![error, sum]
+    };
+    let [(ax, ay), (bx, by), (cx, cy)] =
+        [a, b, c].map(|p| (difference(p.x, d.x), difference(p.y, d.y)));
+    let lift = |x: &[f64], y: &[f64]| add(&multiply(x, x), &multiply(y, y));
+    let cross = |x1: &[f64], y1: &[f64], x2: &[f64], y2: &[f64]| {
+        add(&multiply(x1, y2), &negate(&multiply(x2, y1)))
+    };
+    let det = add(
+        &add(
+            &multiply(&lift(&ax, &ay), &cross(&bx, &by, &cx, &cy)),
+            &multiply(&lift(&bx, &by), &cross(&cx, &cy, &ax, &ay)),
+        ),
+        &multiply(&lift(&cx, &cy), &cross(&ax, &ay, &bx, &by)),
+    );
+    estimate(&det)
+}
+
+/// Returns `a + b` and the rounding error, so that the two sum exactly to `a + b`.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Returns `a * b` and the rounding error, so that the two sum exactly to `a * b`.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    (product, a.mul_add(b, -product))
+}
+
+/// Adds `b` to the expansion `e` (a sum of non-overlapping components, smallest first), leaving out zeros.
+fn grow(e: &mut Vec<f64>, b: f64) {
+    let mut q = b;
+    let mut out = Vec::with_capacity(e.len() + 1);
+    for &component in &*e {
+        let (sum, error) = two_sum(q, component);
+        if error != 0.0 {
+            out.push(error);
+        }
+        q = sum;
+    }
+    if q != 0.0 {
+        out.push(q);
+    }
+    *e = out;
+}
+
+fn add(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut sum = e.to_vec();
+    for &component in f {
+        grow(&mut sum, component);
+    }
+    sum
+}
+
+fn negate(e: &[f64]) -> Vec<f64> {
+    e.iter().map(|x| -x).collect()
+}
+
+fn multiply(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut product = vec![];
+    for &x in e {
+        for &y in f {
+            let (high, low) = two_product(x, y);
+            grow(&mut product, low);
+            grow(&mut product, high);
+        }
+    }
+    product
+}
+
+/// Returns the largest component of an expansion, which has the sign of the whole.
+fn estimate(e: &[f64]) -> f64 {
+    e.last().copied().unwrap_or(0.0)
+}
@@ -0,0 +1,256 @@
+//! Point sampling.
+use crate::Vec2;
+#[cfg(feature = "rand")]
+use {crate::Rect2, rand::Rng};
+
+/// Poisson-disk sampling of `rect` ([Bridson's algorithm](https://www.cs.ubc.ca/~rbridson/docs/bridson-siggraph07-poissondisk.pdf)).
+///
+/// Returns points that are at least `min_distance` apart, packed so that no more can be added,
+/// giving a well spaced "blue noise" distribution.
+/// ```
+/// # use vecto::{Rect2, Vec2, sample::poisson_disk};
+/// # use rand::{SeedableRng, rngs::StdRng};
+/// let rect = Rect2::new(Vec2::ZERO, Vec2::new(20.0, 10.0));
+/// let points = poisson_disk(rect, 1.5, &mut StdRng::seed_from_u64(4));
+/// assert!(points.len() > 20);
+/// for (i, a) in points.iter().enumerate() {
+///     assert!(rect.contains_point(*a));
+///     assert!(points[i + 1..].iter().all(|b| a.distance_to(b) >= 1.5));
+/// }
+/// ```
+///
+/// # Panics
+///
+/// If `min_distance` is not positive.
+#[cfg(feature = "rand")]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn poisson_disk(rect: Rect2<f32>, min_distance: f32, rng: &mut impl Rng) -> Vec<Vec2> {
+    /// Candidates tried around a point before it is retired.
+    const ATTEMPTS: usize = 30;
+    assert!(min_distance > 0.0, "min_distance must be positive");
+    if rect.size.x <= 0.0 || rect.size.y <= 0.0 {
+        return vec![];
+    }
+    // each cell can hold at most one point
+    let cell = min_distance / std::f32::consts::SQRT_2;
+    let cols = (rect.size.x / cell).ceil() as usize;
+    let rows = (rect.size.y / cell).ceil() as usize;
+    let mut grid: Vec<Option<usize>> = vec![None; cols * rows];
+    let cell_of = |p: Vec2| {
+        let c = (p - rect.position) / cell;
+        ((c.x as usize).min(cols - 1), (c.y as usize).min(rows - 1))
+    };
+
+    let first = rect.position + Vec2::new(rng.gen(), rng.gen()) * rect.size;
+    let (x, y) = cell_of(first);
+    grid[y * cols + x] = Some(0);
+    let mut points = vec![first];
+    let mut active = vec![0];
+    while !active.is_empty() {
+        let i = rng.gen_range(0..active.len());
+        let around = points[active[i]];
+        let found = (0..ATTEMPTS).find_map(|_| {
+            let angle = rng.gen::<f32>() * std::f32::consts::TAU;
+            let candidate =
+                around + Vec2::from_angle(angle) * (min_distance * (1.0 + rng.gen::<f32>()));
+            if !rect.contains_point(candidate) {
+                return None;
+            }
+            let (x, y) = cell_of(candidate);
+            let crowded = (y.saturating_sub(2)..(y + 3).min(rows))
+                .flat_map(|y| (x.saturating_sub(2)..(x + 3).min(cols)).map(move |x| (x, y)))
+                .filter_map(|(x, y)| grid[y * cols + x])
+                .any(|j| points[j].distance_to(&candidate) < min_distance);
+            (!crowded).then_some((candidate, y * cols + x))
+        });
+        match found {
+            Some((p, cell)) => {
+                grid[cell] = Some(points.len());
+                active.push(points.len());
+                points.push(p);
+            }
+            None => {
+                active.swap_remove(i);
+            }
+        }
+    }
+    points
+}
+
+/// Jittered grid sampling of `rect`: splits it into `cells_x` by `cells_y` cells and picks one random point in each, in row-major order.
+///
+/// Cheaper than [`poisson_disk`], while still avoiding the clumping of uniform random points.
+/// ```
+/// # use vecto::{Rect2, Vec2, sample::jittered_grid};
+/// # use rand::{SeedableRng, rngs::StdRng};
+/// let rect = Rect2::new(Vec2::ZERO, Vec2::new(4.0, 2.0));
+/// let points = jittered_grid(rect, 4, 2, &mut StdRng::seed_from_u64(4));
+/// assert_eq!(points.len(), 8);
+/// // the second point is in the second cell
+/// assert!(Rect2::new(Vec2::new(1.0, 0.0), Vec2::splat(1.0)).contains_point(points[1]));
+/// ```
+#[cfg(feature = "rand")]
+#[allow(clippy::cast_precision_loss)]
+pub fn jittered_grid(
+    rect: Rect2<f32>,
+    cells_x: usize,
+    cells_y: usize,
+    rng: &mut impl Rng,
+) -> Vec<Vec2> {
+    let cell = rect.size / Vec2::new(cells_x as f32, cells_y as f32);
+    let mut points = Vec::with_capacity(cells_x * cells_y);
+    for y in 0..cells_y {
+        for x in 0..cells_x {
+            let jitter = Vec2::new(rng.gen(), rng.gen());
+            points.push(rect.position + (Vec2::new(x as f32, y as f32) + jitter) * cell);
+        }
+    }
+    points
+}
+
+/// Samples a normally distributed point around `mean`, with standard deviation `sigma.x` along X and `sigma.y` along Y.
+/// ```
+/// # use vecto::{Vec2, sample::gaussian};
+/// # use rand::{SeedableRng, rngs::StdRng};
+/// let mut rng = StdRng::seed_from_u64(4);
+/// let points: Vec<_> = (0..10_000).map(|_| gaussian(Vec2::splat(5.0), Vec2::new(1.0, 3.0), &mut rng)).collect();
+/// let mean = points.iter().sum::<Vec2>() / points.len() as f32;
+/// assert!(mean.distance_to(&Vec2::splat(5.0)) < 0.1);
+/// let variance = points.iter().map(|&p| (p - mean) * (p - mean)).sum::<Vec2>() / points.len() as f32;
+/// assert!((variance.x - 1.0).abs() < 0.1 && (variance.y - 9.0).abs() < 0.5);
+/// ```
+#[cfg(feature = "rand")]
+pub fn gaussian(mean: Vec2, sigma: Vec2, rng: &mut impl Rng) -> Vec2 {
+    // Box-Muller, which gives two independent standard normals
+    let u = 1.0 - rng.gen::<f32>(); // in (0, 1], so ln(u) is finite
+    let r = (-2.0 * u.ln()).sqrt();
+    mean + Vec2::from_angle(rng.gen::<f32>() * std::f32::consts::TAU) * r * sigma
+}
+
+/// Samples a normally distributed point around `mean`, with the same standard deviation `sigma` in every direction.
+#[cfg(feature = "rand")]
+pub fn isotropic_gaussian(mean: Vec2, sigma: f32, rng: &mut impl Rng) -> Vec2 {
+    gaussian(mean, Vec2::splat(sigma), rng)
+}
+
+#[cfg(feature = "rand")]
+impl Vec2 {
+    /// Returns the vector moved by normally distributed noise with standard deviation `sigma`.
+    /// ```
+    /// # use vecto::Vec2;
+    /// # use rand::{SeedableRng, rngs::StdRng};
+    /// let spawn = Vec2::new(10.0, 10.0).random_jitter(&mut StdRng::seed_from_u64(4), 0.5);
+    /// assert!(spawn != Vec2::new(10.0, 10.0) && spawn.distance_to(&Vec2::new(10.0, 10.0)) < 5.0);
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn random_jitter(self, rng: &mut impl Rng, sigma: f32) -> Self {
+        isotropic_gaussian(self, sigma, rng)
+    }
+}
+
+/// The [Halton sequence](https://en.wikipedia.org/wiki/Halton_sequence), a low-discrepancy sequence of points in the unit square.
+///
+/// Use [`Rect2::point_at`](crate::Rect2::point_at) to map the points into a rectangle.
+/// ```
+/// # use vecto::{Vec2, sample::Halton};
+/// let mut h = Halton::new();
+/// assert_eq!(h.next(), Some(Vec2::new(0.5, 1.0 / 3.0)));
+/// assert_eq!(h.next(), Some(Vec2::new(0.25, 2.0 / 3.0)));
+/// assert_eq!(h.next(), Some(Vec2::new(0.75, 1.0 / 9.0)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Halton {
+    index: u32,
+    bases: (u32, u32),
+}
+
+impl Halton {
+    /// Construct the standard 2, 3 Halton sequence.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_bases(2, 3)
+    }
+
+    /// Construct a Halton sequence with custom bases. The bases should be coprime.
+    ///
+    /// # Panics
+    ///
+    /// If either base is less than 2.
+    #[must_use]
+    pub const fn with_bases(x: u32, y: u32) -> Self {
+        assert!(x >= 2 && y >= 2, "bases must be at least 2");
+        Self {
+            index: 0,
+            bases: (x, y),
+        }
+    }
+}
+
+impl Default for Halton {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reverses the base `base` digits of `i` about the radix point.
+#[allow(clippy::cast_precision_loss)]
+fn radical_inverse(mut i: u32, base: u32) -> f32 {
+    let mut f = 1.0;
+    let mut r = 0.0;
+    while i > 0 {
+        f /= base as f32;
+        r += f * (i % base) as f32;
+        i /= base;
+    }
+    r
+}
+
+impl Iterator for Halton {
+    type Item = Vec2;
+
+    fn next(&mut self) -> Option<Vec2> {
+        // 0 would give the origin, so start at 1
+        self.index = self.index.checked_add(1)?;
+        Some(Vec2::new(
+            radical_inverse(self.index, self.bases.0),
+            radical_inverse(self.index, self.bases.1),
+        ))
+    }
+}
+
+/// The [R2 sequence](https://extremelearning.com.au/unreasonable-effectiveness-of-quasirandom-sequences/), a low-discrepancy sequence of points in the unit square based on the plastic number.
+///
+/// Cheaper than [`Halton`] and more evenly spread for arbitrary counts.
+/// ```
+/// # use vecto::{Rect2, Vec2, sample::R2};
+/// let rect = Rect2::new(Vec2::splat(-1.0), Vec2::splat(2.0));
+/// assert!(R2::new().take(100).map(|p| rect.point_at(p)).all(|p| rect.contains_point(p)));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct R2 {
+    index: u32,
+}
+
+impl R2 {
+    /// Construct a new R2 sequence.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { index: 0 }
+    }
+}
+
+impl Iterator for R2 {
+    type Item = Vec2;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn next(&mut self) -> Option<Vec2> {
+        /// The plastic number, the real root of x³ = x + 1.
+        const G: f64 = 1.324_717_957_244_746;
+        let n = f64::from(self.index);
+        self.index = self.index.checked_add(1)?;
+        Some(Vec2::new(
+            (0.5 + n / G).fract() as f32,
+            (0.5 + n / (G * G)).fract() as f32,
+        ))
+    }
+}
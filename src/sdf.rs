@@ -0,0 +1,150 @@
+//! Signed distance functions: the distance from a point to a shape's edge, negative inside.
+//!
+//! Shapes are centered on the origin; move `p` the other way to move them.
+//! ```
+//! # use vecto::{Vec2, sdf};
+//! // a pill with a round bite taken out
+//! let shape = |p: Vec2| {
+//!     let pill = sdf::segment(p, Vec2::new(-2.0, 0.0), Vec2::new(2.0, 0.0)) - 1.0;
+//!     let bite = sdf::circle(p - Vec2::new(2.5, 0.0), 1.0);
+//!     sdf::subtract(pill, bite)
+//! };
+//! assert!(shape(Vec2::ZERO) < 0.0);
+//! assert!(shape(Vec2::new(2.6, 0.0)) > 0.0);
+//! ```
+use crate::Vec2;
+
+/// Returns the signed distance from `p` to a circle of `radius`.
+#[must_use]
+#[inline]
+pub fn circle(p: Vec2, radius: f32) -> f32 {
+    p.length() - radius
+}
+
+/// Returns the signed distance from `p` to an axis-aligned box reaching `half_size` from the center.
+/// ```
+/// # use vecto::{Vec2, sdf};
+/// let half = Vec2::new(2.0, 1.0);
+/// assert_eq!(sdf::rect(Vec2::new(5.0, 0.0), half), 3.0);
+/// assert_eq!(sdf::rect(Vec2::new(5.0, 5.0), half), 5.0);
+/// assert_eq!(sdf::rect(Vec2::ZERO, half), -1.0);
+/// ```
+#[must_use]
+#[inline]
+pub fn rect(p: Vec2, half_size: Vec2) -> f32 {
+    let d = p.abs() - half_size;
+    let outside = d.map(|c| c.max(0.0)).length();
+    outside + d.max_element().min(0.0)
+}
+
+/// Returns the signed distance from `p` to an axis-aligned box reaching `half_size` from the center,
+/// with its corners rounded to `radius`.
+/// ```
+/// # use vecto::{Vec2, sdf};
+/// let half = Vec2::splat(2.0);
+/// assert_eq!(sdf::rounded_rect(Vec2::new(3.0, 0.0), half, 0.5), 1.0);
+/// // the corner is cut back
+/// assert!(sdf::rounded_rect(Vec2::splat(1.9), half, 0.5) > 0.0);
+/// ```
+#[must_use]
+#[inline]
+pub fn rounded_rect(p: Vec2, half_size: Vec2, radius: f32) -> f32 {
+    rect(p, half_size - Vec2::splat(radius)) - radius
+}
+
+/// Returns the distance from `p` to the segment from `a` to `b`. Never negative; subtract a radius for a capsule.
+/// ```
+/// # use vecto::{Vec2, sdf};
+/// let (a, b) = (Vec2::ZERO, Vec2::new(4.0, 0.0));
+/// assert_eq!(sdf::segment(Vec2::new(2.0, 3.0), a, b), 3.0);
+/// assert_eq!(sdf::segment(Vec2::new(7.0, 4.0), a, b), 5.0);
+/// ```
+#[must_use]
+#[inline]
+pub fn segment(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let (pa, ba) = (p - a, b - a);
+    let len = ba.length_squared();
+    let t = if len == 0.0 {
+        0.0
+    } else {
+        (pa.dot(&ba) / len).clamp(0.0, 1.0)
+    };
+    (pa - ba * t).length()
+}
+
+/// Returns the signed distance from `p` to the polygon through `vertices` (in either winding), inside by the even-odd rule.
+/// ```
+/// # use vecto::{Vec2, sdf};
+/// let triangle = [Vec2::ZERO, Vec2::new(4.0, 0.0), Vec2::new(0.0, 4.0)];
+/// assert_eq!(sdf::polygon(Vec2::new(1.0, 1.0), &triangle), -1.0);
+/// assert_eq!(sdf::polygon(Vec2::new(-3.0, 2.0), &triangle), 3.0);
+/// ```
+#[must_use]
+pub fn polygon(p: Vec2, vertices: &[Vec2]) -> f32 {
+    let mut distance = f32::INFINITY;
+    let mut inside = false;
+    for (i, &a) in vertices.iter().enumerate() {
+        let b = vertices[(i + 1) % vertices.len()];
+        distance = distance.min(segment(p, a, b));
+        if (a.y > p.y) != (b.y > p.y) && p.x < a.x + (p.y - a.y) / (b.y - a.y) * (b.x - a.x) {
+            inside = !inside;
+        }
+    }
+    if inside {
+        -distance
+    } else {
+        distance
+    }
+}
+
+/// Returns the distance to the union of two shapes, given the distances to each.
+#[must_use]
+#[inline]
+pub fn union(a: f32, b: f32) -> f32 {
+    a.min(b)
+}
+
+/// Returns the distance to the intersection of two shapes, given the distances to each.
+#[must_use]
+#[inline]
+pub fn intersection(a: f32, b: f32) -> f32 {
+    a.max(b)
+}
+
+/// Returns the distance to shape `a` with shape `b` cut out of it, given the distances to each.
+#[must_use]
+#[inline]
+pub fn subtract(a: f32, b: f32) -> f32 {
+    a.max(-b)
+}
+
+/// Returns the distance to the union of two shapes blended together within `k` of where they meet,
+/// with a polynomial smooth minimum.
+/// ```
+/// # use vecto::sdf;
+/// assert_eq!(sdf::smooth_union(1.0, 5.0, 0.5), 1.0); // far apart, so unchanged
+/// assert!(sdf::smooth_union(1.0, 1.0, 0.5) < 1.0); // bulges where they meet
+/// ```
+#[must_use]
+#[inline]
+pub fn smooth_union(a: f32, b: f32, k: f32) -> f32 {
+    if k <= 0.0 {
+        return a.min(b);
+    }
+    let h = (k - (a - b).abs()).max(0.0) / k;
+    a.min(b) - h * h * k / 4.0
+}
+
+/// Returns the distance to the intersection of two shapes, with the edge where they meet rounded within `k`.
+#[must_use]
+#[inline]
+pub fn smooth_intersection(a: f32, b: f32, k: f32) -> f32 {
+    -smooth_union(-a, -b, k)
+}
+
+/// Returns the distance to shape `a` with shape `b` cut out of it, with the edge of the cut rounded within `k`.
+#[must_use]
+#[inline]
+pub fn smooth_subtract(a: f32, b: f32, k: f32) -> f32 {
+    smooth_intersection(a, -b, k)
+}
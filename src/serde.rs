@@ -0,0 +1,186 @@
+//! [`serde`](::serde) support.
+//!
+//! [`Vector2`] serializes as a struct, `{"x": .., "y": ..}`, and deserializes from any of
+//! - that struct,
+//! - a sequence, `[x, y]`,
+//! - a single number, which is splatted,
+//! - or a string, `"x,y"`.
+//!
+//! Accepting all of these needs a self-describing format, such as JSON. For other formats,
+//! or to reject everything but the struct, use [`strict`].
+//! ```
+//! # use vecto::Vec2;
+//! for json in [r#"{"x": 1, "y": 2}"#, "[1, 2]", r#""1, 2""#] {
+//!     assert_eq!(serde_json::from_str::<Vec2>(json).unwrap(), Vec2::new(1.0, 2.0));
+//! }
+//! assert_eq!(serde_json::from_str::<Vec2>("3").unwrap(), Vec2::splat(3.0));
+//! assert_eq!(serde_json::to_string(&Vec2::new(1.0, 2.0)).unwrap(), r#"{"x":1.0,"y":2.0}"#);
+//! ```
+use crate::Vector2;
+use ::serde::{
+    de::{self, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeStruct, Serializer},
+    Deserialize, Serialize,
+};
+use core::{fmt, marker::PhantomData, str::FromStr};
+
+const FIELDS: &[&str] = &["x", "y"];
+
+impl<T: Serialize> Serialize for Vector2<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Vector2", 2)?;
+        s.serialize_field("x", &self.x)?;
+        s.serialize_field("y", &self.y)?;
+        s.end()
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Vector2<T>
+where
+    T: Deserialize<'de> + Copy + FromStr,
+    T::Err: fmt::Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(Flexible(PhantomData))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    X,
+    Y,
+}
+
+/// Visits the `{x, y}` struct, or a sequence.
+struct Strict<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for Strict<T> {
+    type Value = Vector2<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a Vector2 struct")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let x = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let y = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(3, &self));
+        }
+        Ok(Vector2::new(x, y))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let (mut x, mut y) = (None, None);
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::X if x.is_some() => return Err(de::Error::duplicate_field("x")),
+                Field::Y if y.is_some() => return Err(de::Error::duplicate_field("y")),
+                Field::X => x = Some(map.next_value()?),
+                Field::Y => y = Some(map.next_value()?),
+            }
+        }
+        Ok(Vector2::new(
+            x.ok_or_else(|| de::Error::missing_field("x"))?,
+            y.ok_or_else(|| de::Error::missing_field("y"))?,
+        ))
+    }
+}
+
+/// Also visits numbers and strings.
+struct Flexible<T>(PhantomData<T>);
+
+impl<T: Copy> Flexible<T> {
+    fn splat<'de, E: de::Error>(value: impl IntoDeserializer<'de, E>) -> Result<Vector2<T>, E>
+    where
+        T: Deserialize<'de>,
+    {
+        T::deserialize(value.into_deserializer()).map(Vector2::splat)
+    }
+}
+
+impl<'de, T> Visitor<'de> for Flexible<T>
+where
+    T: Deserialize<'de> + Copy + FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = Vector2<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(r#"a Vector2 struct, [x, y], a number, or "x,y""#)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        Strict(PhantomData).visit_seq(seq)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        Strict(PhantomData).visit_map(map)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Self::splat(v)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Self::splat(v)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Self::splat(v)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let (x, y) = v
+            .split_once(',')
+            .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(v), &self))?;
+        let parse = |c: &str| c.trim().parse().map_err(de::Error::custom);
+        Ok(Vector2::new(parse(x)?, parse(y)?))
+    }
+}
+
+/// Only accepts the serialized form (or, like derived impls, a sequence), for `#[serde(with = "vecto::serde::strict")]`.
+/// Works with non self-describing formats.
+/// ```
+/// # use vecto::Vec2;
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     #[serde(with = "vecto::serde::strict")]
+///     spawn: Vec2,
+/// }
+/// let c: Config = serde_json::from_str(r#"{"spawn": {"x": 1, "y": 2}}"#).unwrap();
+/// assert_eq!(c.spawn, Vec2::new(1.0, 2.0));
+/// assert!(serde_json::from_str::<Config>(r#"{"spawn": "1,2"}"#).is_err());
+/// ```
+pub mod strict {
+    use super::{Deserialize, Deserializer, PhantomData, Serialize, Serializer, Strict, FIELDS};
+    use crate::Vector2;
+
+    /// Serializes `v` as a struct. Same as [`Serialize`].
+    ///
+    /// # Errors
+    ///
+    /// If the serializer fails.
+    pub fn serialize<T: Serialize, S: Serializer>(
+        v: &Vector2<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        v.serialize(serializer)
+    }
+
+    /// Deserializes a struct, `{x, y}`.
+    ///
+    /// # Errors
+    ///
+    /// If the input isn't a struct with `x` and `y`.
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vector2<T>, D::Error> {
+        deserializer.deserialize_struct("Vector2", FIELDS, Strict(PhantomData))
+    }
+}
@@ -0,0 +1,330 @@
+//! Boolean operations on polygons, with the [Greiner–Hormann](https://en.wikipedia.org/wiki/Greiner%E2%80%93Hormann_clipping_algorithm) algorithm.
+use super::Polygon;
+use crate::Vec2;
+
+/// A vertex of one polygon's outline, with the crossings spliced in.
+struct Node {
+    point: Vec2,
+    /// For crossings, the same crossing in the other outline.
+    neighbor: Option<usize>,
+    /// For crossings, whether following the outline forwards from here goes into the result.
+    forwards: bool,
+    visited: bool,
+}
+
+/// A crossing of edge `a` of the first polygon at `t`, and edge `b` of the second at `u`.
+struct Crossing {
+    a: usize,
+    t: f32,
+    b: usize,
+    u: f32,
+}
+
+/// How close to an edge's end a crossing counts as touching a vertex.
+const EPSILON: f32 = 1e-5;
+
+/// Returns every crossing of the edges of `a` and `b`, and whether any of them are degenerate
+/// (touching a vertex, or overlapping edges).
+fn crossings(first: &Polygon, second: &Polygon) -> (Vec<Crossing>, bool) {
+    let mut found = vec![];
+    let mut degenerate = false;
+    for (a, (from_a, to_a)) in first.edges().enumerate() {
+        for (b, (from_b, to_b)) in second.edges().enumerate() {
+            let (along_a, along_b, between) = (to_a - from_a, to_b - from_b, from_b - from_a);
+            let denominator = along_a.cross(&along_b);
+            if denominator.abs() <= EPSILON * along_a.length() * along_b.length() {
+                // parallel: only a problem if they overlap
+                let collinear =
+                    between.cross(&along_a).abs() <= EPSILON * along_a.length() * between.length();
+                let (start, end) = (between.dot(&along_a), (between + along_b).dot(&along_a));
+                degenerate |= collinear
+                    && start.max(end) >= 0.0
+                    && start.min(end) <= along_a.length_squared();
+                continue;
+            }
+            let t = between.cross(&along_b) / denominator;
+            let u = between.cross(&along_a) / denominator;
+            let within = |x: f32| (-EPSILON..=1.0 + EPSILON).contains(&x);
+            let inside = |x: f32| (EPSILON..=1.0 - EPSILON).contains(&x);
+            if within(t) && within(u) {
+                if inside(t) && inside(u) {
+                    found.push(Crossing { a, t, b, u });
+                } else {
+                    degenerate = true;
+                }
+            }
+        }
+    }
+    (found, degenerate)
+}
+
+/// Returns an outline's nodes, with the crossings (given as edge, position along it and crossing index) spliced in,
+/// and the index of each crossing's node.
+fn outline(polygon: &Polygon, mut crossings: Vec<(usize, f32, usize)>) -> (Vec<Node>, Vec<usize>) {
+    crossings.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    let mut nodes = Vec::with_capacity(polygon.vertices.len() + crossings.len());
+    let mut indices = vec![0; crossings.len()];
+    let mut crossings = crossings.into_iter().peekable();
+    for (i, (p, p1)) in polygon.edges().enumerate() {
+        nodes.push(Node {
+            point: p,
+            neighbor: None,
+            forwards: false,
+            visited: false,
+        });
+        while let Some((_, t, crossing)) = crossings.next_if(|&(edge, _, _)| edge == i) {
+            indices[crossing] = nodes.len();
+            nodes.push(Node {
+                point: p.lerp(p1, t),
+                neighbor: Some(0),
+                forwards: false,
+                visited: false,
+            });
+        }
+    }
+    (nodes, indices)
+}
+
+/// Clips `a` against `b`, following each outline forwards (`true`) or backwards from where it enters the other.
+/// Returns [`None`] if the outlines don't cross.
+fn clip(a: &Polygon, b: &Polygon, forwards: [bool; 2]) -> Option<Vec<Polygon>> {
+    // vertices exactly on the other outline confuse the algorithm, so nudge `b` off them
+    let scale = a
+        .vertices
+        .iter()
+        .chain(&b.vertices)
+        .fold(1.0f32, |scale, p| scale.max(p.abs().max_element()));
+    let a = &a.clone().wound_positive();
+    let mut b = b.clone().wound_positive();
+    let mut found = crossings(a, &b);
+    for attempt in 1..=8 {
+        if !found.1 {
+            break;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let nudge = Vec2::new(0.6, 0.8) * scale * EPSILON * attempt as f32;
+        b.vertices.iter_mut().for_each(|p| *p += nudge);
+        found = crossings(a, &b);
+    }
+    let found = found.0;
+    if found.is_empty() {
+        return None;
+    }
+    let (mut first, in_first) = outline(
+        a,
+        found
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.a, c.t, i))
+            .collect(),
+    );
+    let (mut second, in_second) = outline(
+        &b,
+        found
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.b, c.u, i))
+            .collect(),
+    );
+    for (&i, &j) in in_first.iter().zip(&in_second) {
+        first[i].neighbor = Some(j);
+        second[j].neighbor = Some(i);
+    }
+    // crossings alternate between entering and leaving the other polygon
+    let starts_inside = [
+        b.contains_point(a.vertices[0]),
+        a.contains_point(b.vertices[0]),
+    ];
+    for ((nodes, forwards), inside) in [&mut first, &mut second]
+        .into_iter()
+        .zip(forwards)
+        .zip(starts_inside)
+    {
+        let mut forwards = forwards ^ inside;
+        for node in nodes.iter_mut().filter(|n| n.neighbor.is_some()) {
+            node.forwards = forwards;
+            forwards = !forwards;
+        }
+    }
+    let mut outlines = [first, second];
+    let mut result = vec![];
+    for start in 0..outlines[0].len() {
+        if outlines[0][start].neighbor.is_none() || outlines[0][start].visited {
+            continue;
+        }
+        let (mut side, mut i) = (0, start);
+        let mut vertices = vec![outlines[0][start].point];
+        loop {
+            let neighbor = outlines[side][i].neighbor.unwrap_or_default();
+            outlines[side][i].visited = true;
+            outlines[1 - side][neighbor].visited = true;
+            let forwards = outlines[side][i].forwards;
+            let len = outlines[side].len();
+            loop {
+                i = if forwards {
+                    (i + 1) % len
+                } else {
+                    (i + len - 1) % len
+                };
+                vertices.push(outlines[side][i].point);
+                if outlines[side][i].neighbor.is_some() {
+                    break;
+                }
+            }
+            i = outlines[side][i].neighbor.unwrap_or_default();
+            side = 1 - side;
+            if outlines[side][i].visited {
+                break;
+            }
+        }
+        // back at the start
+        vertices.pop();
+        result.push(Polygon::new(vertices).wound_positive());
+    }
+    Some(result)
+}
+
+impl Polygon {
+    /// Returns a copy of the polygon with positive winding.
+    fn wound_positive(self) -> Self {
+        if self.signed_area() < 0.0 {
+            self.reversed()
+        } else {
+            self
+        }
+    }
+
+    /// Returns true if the first vertex is inside `other`. For polygons that don't cross, whether this one is inside `other`.
+    fn starts_inside(&self, other: &Self) -> bool {
+        self.vertices
+            .first()
+            .is_some_and(|&p| other.contains_point(p))
+    }
+
+    /// Returns the area covered by both polygons, as zero or more polygons with positive winding.
+    ///
+    /// Both polygons must be simple (not self-intersecting). Vertices lying exactly on the other polygon's edges
+    /// are handled by nudging it by a tiny amount, which can leave slivers.
+    /// ```
+    /// # use vecto::{Vec2, shape::Polygon};
+    /// let square = |at: Vec2| Polygon::new([(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)].map(|p| at + p).to_vec());
+    /// let (a, b) = (square(Vec2::ZERO), square(Vec2::splat(1.0)));
+    /// let both = a.intersection(&b);
+    /// assert_eq!(both.len(), 1);
+    /// assert!((both[0].area() - 1.0).abs() < 1e-5);
+    /// assert!(a.intersection(&square(Vec2::splat(5.0))).is_empty());
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Vec<Self> {
+        clip(self, other, [true, true]).unwrap_or_else(|| {
+            if self.starts_inside(other) {
+                vec![self.clone().wound_positive()]
+            } else if other.starts_inside(self) {
+                vec![other.clone().wound_positive()]
+            } else {
+                vec![]
+            }
+        })
+    }
+
+    /// Returns the area covered by either polygon, as one or more polygons with positive winding.
+    /// See [`Polygon::intersection`] for the requirements.
+    ///
+    /// Areas enclosed by the polygons but in neither are returned as holes, with negative winding.
+    /// ```
+    /// # use vecto::{Vec2, shape::Polygon};
+    /// let square = |at: Vec2| Polygon::new([(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)].map(|p| at + p).to_vec());
+    /// let (a, b) = (square(Vec2::ZERO), square(Vec2::splat(1.0)));
+    /// let either = a.union(&b);
+    /// assert_eq!(either.len(), 1);
+    /// assert!((either[0].area() - 7.0).abs() < 1e-5);
+    /// assert_eq!(a.union(&square(Vec2::splat(5.0))).len(), 2);
+    /// // a U closed by a bar encloses a hole
+    /// let u = Polygon::new([(0.0, 0.0), (1.0, 0.0), (1.0, 3.0), (2.0, 3.0), (2.0, 0.0), (3.0, 0.0), (3.0, 4.0), (0.0, 4.0)].map(Vec2::from).to_vec());
+    /// let bar = Polygon::new([(-1.0, 1.0), (4.0, 1.0), (4.0, 2.0), (-1.0, 2.0)].map(Vec2::from).to_vec());
+    /// let mut areas: Vec<_> = u.union(&bar).iter().map(Polygon::signed_area).collect();
+    /// areas.sort_by(f32::total_cmp);
+    /// assert_eq!(areas, [-1.0, 13.0]);
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Vec<Self> {
+        let Some(mut result) = clip(self, other, [false, false]) else {
+            return if self.starts_inside(other) {
+                vec![other.clone().wound_positive()]
+            } else if other.starts_inside(self) {
+                vec![self.clone().wound_positive()]
+            } else {
+                vec![
+                    self.clone().wound_positive(),
+                    other.clone().wound_positive(),
+                ]
+            };
+        };
+        // only a union can enclose an area that is in neither polygon
+        let holes: Vec<bool> = (0..result.len())
+            .map(|i| {
+                let around = result
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, outline)| j != i && result[i].starts_inside(outline))
+                    .count();
+                around % 2 == 1
+            })
+            .collect();
+        for (polygon, hole) in result.iter_mut().zip(holes) {
+            if hole {
+                polygon.vertices.reverse();
+            }
+        }
+        result
+    }
+
+    /// Returns the area covered by this polygon but not `other`, as zero or more polygons with positive winding.
+    /// See [`Polygon::intersection`] for the requirements.
+    ///
+    /// If `other` is entirely inside this polygon, it is returned as a hole, with negative winding, after this polygon.
+    /// ```
+    /// # use vecto::{Vec2, shape::Polygon};
+    /// let square = |at: Vec2, size: f32| Polygon::new([(0.0, 0.0), (size, 0.0), (size, size), (0.0, size)].map(|p| at + p).to_vec());
+    /// let terrain = square(Vec2::ZERO, 4.0);
+    /// // blast a corner off
+    /// let left = terrain.difference(&square(Vec2::splat(3.0), 2.0));
+    /// assert_eq!(left.len(), 1);
+    /// assert!((left[0].area() - 15.0).abs() < 1e-4);
+    /// // and a hole in the middle
+    /// let holed = left[0].difference(&square(Vec2::splat(1.0), 1.0));
+    /// assert_eq!(holed.len(), 2);
+    /// assert!(holed[1].signed_area() < 0.0);
+    /// ```
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        clip(self, other, [false, true]).unwrap_or_else(|| {
+            if self.starts_inside(other) {
+                vec![]
+            } else if other.starts_inside(self) {
+                vec![
+                    self.clone().wound_positive(),
+                    other.clone().wound_positive().reversed(),
+                ]
+            } else {
+                vec![self.clone().wound_positive()]
+            }
+        })
+    }
+
+    /// Returns the area covered by exactly one of the polygons: this minus `other`, then `other` minus this.
+    /// See [`Polygon::difference`] for the result.
+    /// ```
+    /// # use vecto::{Vec2, shape::Polygon};
+    /// let square = |at: Vec2| Polygon::new([(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)].map(|p| at + p).to_vec());
+    /// let either = square(Vec2::ZERO).xor(&square(Vec2::splat(1.0)));
+    /// assert_eq!(either.len(), 2);
+    /// assert!((either.iter().map(Polygon::area).sum::<f32>() - 6.0).abs() < 1e-5);
+    /// ```
+    #[must_use]
+    pub fn xor(&self, other: &Self) -> Vec<Self> {
+        let mut result = self.difference(other);
+        result.extend(other.difference(self));
+        result
+    }
+}
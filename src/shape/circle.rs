@@ -0,0 +1,73 @@
+use crate::Vec2;
+
+/// A circle, as a center and a radius.
+/// ```
+/// # use vecto::{Vec2, shape::Circle};
+/// let c = Circle::new(Vec2::new(1.0, 1.0), 2.0);
+/// assert!(c.contains_point(Vec2::new(2.0, 2.0)));
+/// assert_eq!(c.signed_distance_to(Vec2::new(1.0, 5.0)), 2.0);
+/// assert_eq!(c.signed_distance_to(Vec2::new(1.0, 1.0)), -2.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Circle {
+    /// The center.
+    pub center: Vec2,
+    /// The radius.
+    pub radius: f32,
+}
+
+impl Circle {
+    /// Construct a new [`Circle`].
+    #[must_use]
+    #[inline]
+    pub const fn new(center: Vec2, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// Returns the area, `πr²`.
+    #[must_use]
+    #[inline]
+    pub fn area(&self) -> f32 {
+        std::f32::consts::PI * self.radius * self.radius
+    }
+
+    /// Returns the circumference, `τr`.
+    #[must_use]
+    #[inline]
+    pub fn circumference(&self) -> f32 {
+        std::f32::consts::TAU * self.radius
+    }
+
+    /// Returns true if `point` is inside (or on the edge of) the circle.
+    #[must_use]
+    #[inline]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        self.center.distance_squared_to(&point) <= self.radius * self.radius
+    }
+
+    /// Returns the distance from `point` to the edge of the circle, negative inside.
+    #[must_use]
+    #[inline]
+    pub fn signed_distance_to(&self, point: Vec2) -> f32 {
+        self.center.distance_to(&point) - self.radius
+    }
+
+    /// Returns the point on the edge of the circle nearest to `point`. The center projects to the rightmost point.
+    /// ```
+    /// # use vecto::{Vec2, shape::Circle};
+    /// let c = Circle::new(Vec2::ZERO, 2.0);
+    /// assert_eq!(c.project(Vec2::new(0.0, 5.0)), Vec2::new(0.0, 2.0));
+    /// assert_eq!(c.project(Vec2::ZERO), Vec2::new(2.0, 0.0));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn project(&self, point: Vec2) -> Vec2 {
+        let offset = point - self.center;
+        let direction = if offset == Vec2::ZERO {
+            Vec2::RIGHT
+        } else {
+            offset.normalized()
+        };
+        self.center + direction * self.radius
+    }
+}
@@ -0,0 +1,68 @@
+use crate::Vec2;
+
+/// An infinite line, through `point` along the unit vector `direction`.
+/// ```
+/// # use vecto::{Vec2, shape::Line2};
+/// let floor = Line2::through(Vec2::new(0.0, 2.0), Vec2::new(4.0, 2.0));
+/// assert_eq!(floor.distance_to(Vec2::new(7.0, -1.0)), 3.0);
+/// assert_eq!(floor.project(Vec2::new(7.0, -1.0)), Vec2::new(7.0, 2.0));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Line2 {
+    /// A point on the line.
+    pub point: Vec2,
+    /// The unit direction of the line.
+    pub direction: Vec2,
+}
+
+impl Line2 {
+    /// Construct a new [`Line2`] through `point` along `direction`, which is normalized.
+    #[must_use]
+    #[inline]
+    pub fn new(point: Vec2, direction: Vec2) -> Self {
+        Self {
+            point,
+            direction: direction.normalized(),
+        }
+    }
+
+    /// Construct the [`Line2`] through `a` and `b`, directed from `a` to `b`.
+    #[must_use]
+    #[inline]
+    pub fn through(a: Vec2, b: Vec2) -> Self {
+        Self::new(a, b - a)
+    }
+
+    /// Returns the unit normal, the direction rotated a quarter turn.
+    #[must_use]
+    #[inline]
+    pub fn normal(&self) -> Vec2 {
+        self.direction.orthogonal()
+    }
+
+    /// Returns the distance from `p` to the line, positive on the [normal](Line2::normal)'s side.
+    /// ```
+    /// # use vecto::{Vec2, shape::Line2};
+    /// let line = Line2::new(Vec2::ZERO, Vec2::RIGHT);
+    /// assert_eq!(line.signed_distance_to(Vec2::new(5.0, 2.0)), -line.signed_distance_to(Vec2::new(5.0, -2.0)));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn signed_distance_to(&self, p: Vec2) -> f32 {
+        (p - self.point).dot(&self.normal())
+    }
+
+    /// Returns the distance from `p` to the line.
+    #[must_use]
+    #[inline]
+    pub fn distance_to(&self, p: Vec2) -> f32 {
+        self.signed_distance_to(p).abs()
+    }
+
+    /// Returns the point on the line nearest to `p`.
+    #[must_use]
+    #[inline]
+    pub fn project(&self, p: Vec2) -> Vec2 {
+        self.point + self.direction * (p - self.point).dot(&self.direction)
+    }
+}
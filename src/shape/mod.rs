@@ -0,0 +1,14 @@
+//! Geometric shapes.
+mod boolean;
+mod circle;
+mod line;
+mod offset;
+mod polygon;
+mod polyline;
+mod raster;
+pub use circle::Circle;
+pub use line::Line2;
+pub use offset::Join;
+pub use polygon::{minkowski_sum, minkowski_sum_circle, Polygon};
+pub use polyline::Polyline;
+pub use raster::FillRule;
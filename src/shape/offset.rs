@@ -0,0 +1,129 @@
+use super::{Polygon, Polyline};
+use crate::Vec2;
+
+/// How [offset](Polygon::offset) outlines fill the gap at corners.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Join {
+    /// Extend the edges until they meet, unless the point would be more than `limit` times the distance from the corner,
+    /// in which case [`Join::Bevel`]. SVG's default limit is `4.0`.
+    Miter(f32),
+    /// Round the corner off with an arc, split every 15° at most.
+    Round,
+    /// Cut the corner off with a straight edge.
+    Bevel,
+}
+
+/// Offsets the path through `points` by `distance` towards the [orthogonal](Vec2::orthogonal) of each edge.
+fn offset(points: &[Vec2], closed: bool, distance: f32, join: Join) -> Vec<Vec2> {
+    let mut points = points.to_vec();
+    points.dedup();
+    if closed && points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    let n = points.len();
+    if n < 2 || distance == 0.0 {
+        return points;
+    }
+    let normal = |i: usize| (points[(i + 1) % n] - points[i]).normalized().orthogonal();
+    let mut out = Vec::with_capacity(n * 2);
+    for (i, &p) in points.iter().enumerate() {
+        let incoming = if i > 0 || closed {
+            Some(normal((i + n - 1) % n))
+        } else {
+            None
+        };
+        let outgoing = if i + 1 < n || closed {
+            Some(normal(i))
+        } else {
+            None
+        };
+        let (before, after) = match (incoming, outgoing) {
+            (Some(before), Some(after)) => (before, after),
+            (Some(only), None) | (None, Some(only)) => {
+                out.push(p + only * distance);
+                continue;
+            }
+            (None, None) => unreachable!(),
+        };
+        let cos = before.dot(&after);
+        // the edges' offsets overlap rather than leaving a gap, so meet at their crossing
+        let overlapping = before.cross(&after) * distance <= 0.0;
+        if overlapping && cos > -0.999 {
+            out.push(p + (before + after) * (distance / (1.0 + cos)));
+            continue;
+        }
+        match join {
+            Join::Miter(limit) if cos > -0.999 && (2.0 / (1.0 + cos)).sqrt() <= limit => {
+                out.push(p + (before + after) * (distance / (1.0 + cos)));
+            }
+            Join::Round => {
+                let angle = before.perp_dot(&after).atan2(cos);
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let steps = (angle.abs() / 15f32.to_radians()).ceil().max(1.0) as usize;
+                #[allow(clippy::cast_precision_loss)]
+                out.extend(
+                    (0..=steps)
+                        .map(|s| p + before.rotated(angle * s as f32 / steps as f32) * distance),
+                );
+            }
+            _ => out.extend([p + before * distance, p + after * distance]),
+        }
+    }
+    out
+}
+
+impl Polygon {
+    /// Returns the outline moved outwards by `distance`, or inwards if it is negative, keeping the winding.
+    ///
+    /// Insetting by more than a feature's size (e.g. a narrow neck) gives a self-intersecting outline;
+    /// see [`Polygon::union`] to clean it up.
+    /// ```
+    /// # use vecto::{Vec2, shape::{Join, Polygon}};
+    /// let square = Polygon::new(vec![Vec2::ZERO, Vec2::new(2.0, 0.0), Vec2::splat(2.0), Vec2::new(0.0, 2.0)]);
+    /// let margin = square.offset(1.0, Join::Miter(4.0));
+    /// assert_eq!(margin.vertices, [Vec2::splat(-1.0), Vec2::new(3.0, -1.0), Vec2::splat(3.0), Vec2::new(-1.0, 3.0)]);
+    /// assert_eq!(square.offset(1.0, Join::Bevel).vertices.len(), 8);
+    /// let rounded = square.offset(1.0, Join::Round);
+    /// assert!((rounded.area() - (16.0 - 4.0 + std::f32::consts::PI)).abs() < 0.05);
+    /// let inset = square.reversed().offset(-0.5, Join::Round);
+    /// assert_eq!(inset.signed_area(), -1.0);
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn offset(&self, distance: f32, join: Join) -> Self {
+        // on a positively wound polygon, the orthogonal of each edge points out
+        let sign = if self.signed_area() < 0.0 { -1.0 } else { 1.0 };
+        Self::new(offset(&self.vertices, true, distance * sign, join))
+    }
+}
+
+impl Polyline {
+    /// Returns the polyline moved sideways by `distance`: to the left on screen of the direction of travel
+    /// (towards the [orthogonal](Vec2::orthogonal) of each segment), or to the right if it is negative.
+    /// ```
+    /// # use vecto::{Vec2, shape::{Join, Polyline}};
+    /// let path = Polyline::new(vec![Vec2::ZERO, Vec2::new(4.0, 0.0), Vec2::new(4.0, 4.0)]);
+    /// let lane = path.offset(-1.0, Join::Miter(4.0));
+    /// assert_eq!(lane.vertices, [Vec2::new(0.0, 1.0), Vec2::new(3.0, 1.0), Vec2::new(3.0, 4.0)]);
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn offset(&self, distance: f32, join: Join) -> Self {
+        Self::new(offset(&self.vertices, false, distance, join))
+    }
+
+    /// Returns the outline of the polyline drawn with a pen `width` wide, with flat ends, as a polygon with positive winding.
+    /// ```
+    /// # use vecto::{Vec2, shape::{Join, Polyline}};
+    /// let stroke = Polyline::new(vec![Vec2::ZERO, Vec2::new(4.0, 0.0)]).stroke(2.0, Join::Round);
+    /// assert_eq!(stroke.signed_area(), 8.0);
+    /// ```
+    #[must_use]
+    pub fn stroke(&self, width: f32, join: Join) -> Polygon {
+        let mut outline = offset(&self.vertices, false, width / 2.0, join);
+        outline.extend(
+            offset(&self.vertices, false, -width / 2.0, join)
+                .into_iter()
+                .rev(),
+        );
+        Polygon::new(outline)
+    }
+}
@@ -0,0 +1,234 @@
+use super::Circle;
+use crate::{robust::orient2d, Vec2};
+
+/// A polygon, as its vertices in order. The last vertex connects back to the first.
+///
+/// Either winding is allowed. Positive winding means [`Vec2::cross`] of consecutive edges is positive
+/// for a convex polygon, which is clockwise on screen, as Y is down.
+/// ```
+/// # use vecto::{Vec2, shape::Polygon};
+/// let square = Polygon::new(vec![Vec2::ZERO, Vec2::new(2.0, 0.0), Vec2::splat(2.0), Vec2::new(0.0, 2.0)]);
+/// assert_eq!(square.signed_area(), 4.0);
+/// assert!(square.is_convex());
+/// assert!(square.contains_point(Vec2::splat(1.0)));
+/// assert!(!square.contains_point(Vec2::splat(3.0)));
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Polygon {
+    /// The vertices, in order.
+    pub vertices: Vec<Vec2>,
+}
+
+impl Polygon {
+    /// Construct a new [`Polygon`].
+    #[must_use]
+    #[inline]
+    pub const fn new(vertices: Vec<Vec2>) -> Self {
+        Self { vertices }
+    }
+
+    /// Construct a regular polygon with `sides` vertices at `radius` from `center`, the first to the right of the center.
+    /// The winding is positive.
+    /// ```
+    /// # use vecto::{Vec2, shape::Polygon};
+    /// let hexagon = Polygon::regular(Vec2::ZERO, 1.0, 6);
+    /// assert_eq!(hexagon.vertices.len(), 6);
+    /// assert!(hexagon.signed_area() > 0.0);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn regular(center: Vec2, radius: f32, sides: usize) -> Self {
+        let step = std::f32::consts::TAU / sides as f32;
+        Self::new(
+            (0..sides)
+                .map(|i| center + Vec2::from_angle(i as f32 * step) * radius)
+                .collect(),
+        )
+    }
+
+    /// Returns the edges, as pairs of consecutive vertices.
+    pub fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + Clone + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Returns the area, positive if the winding is positive.
+    #[must_use]
+    pub fn signed_area(&self) -> f32 {
+        self.edges().map(|(a, b)| a.cross(&b)).sum::<f32>() / 2.0
+    }
+
+    /// Returns the area.
+    #[must_use]
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    /// Returns a copy of the polygon with its winding reversed.
+    #[must_use = "Does not modify in place."]
+    pub fn reversed(mut self) -> Self {
+        self.vertices.reverse();
+        self
+    }
+
+    /// Returns true if every corner turns the same way. Collinear vertices are allowed.
+    /// ```
+    /// # use vecto::{Vec2, shape::Polygon};
+    /// let arrow = Polygon::new(vec![Vec2::ZERO, Vec2::new(2.0, 1.0), Vec2::new(0.0, 2.0), Vec2::new(1.0, 1.0)]);
+    /// assert!(!arrow.is_convex());
+    /// ```
+    #[must_use]
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        let turns = (0..n).map(|i| {
+            let [a, b, c] = [i, i + 1, i + 2].map(|i| self.vertices[i % n]);
+            orient2d(a, b, c)
+        });
+        let (mut left, mut right) = (false, false);
+        for turn in turns {
+            left |= turn > 0.0;
+            right |= turn < 0.0;
+        }
+        !(left && right)
+    }
+
+    /// Returns true if `point` is inside the polygon, by the even-odd rule.
+    #[must_use]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        self.edges()
+            .filter(|&(a, b)| {
+                (a.y > point.y) != (b.y > point.y)
+                    && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+            })
+            .count()
+            % 2
+            == 1
+    }
+
+    /// Splits a simple (not self-intersecting) polygon into triangles by ear clipping, wound the same way as the polygon.
+    ///
+    /// Holes aren't supported; a polygon with `n` vertices gives `n - 2` triangles. Takes `O(n³)` time in the worst case,
+    /// as finding each ear can test every vertex against every corner.
+    /// ```
+    /// # use vecto::{Vec2, shape::Polygon};
+    /// // an L shape
+    /// let l = Polygon::new(vec![
+    ///     Vec2::ZERO, Vec2::new(1.0, 0.0), Vec2::new(1.0, 2.0),
+    ///     Vec2::new(3.0, 2.0), Vec2::new(3.0, 3.0), Vec2::new(0.0, 3.0),
+    /// ]);
+    /// let triangles = l.triangulate();
+    /// assert_eq!(triangles.len(), 4);
+    /// let area: f32 = triangles.iter().map(|&[a, b, c]| (b - a).cross(&(c - a)) / 2.0).sum();
+    /// assert_eq!(area, l.signed_area());
+    /// ```
+    #[must_use]
+    pub fn triangulate(&self) -> Vec<[Vec2; 3]> {
+        let mut remaining = self.vertices.clone();
+        let flipped = self.signed_area() < 0.0;
+        if flipped {
+            remaining.reverse();
+        }
+        let mut triangles = Vec::with_capacity(remaining.len().saturating_sub(2));
+        while remaining.len() > 3 {
+            let n = remaining.len();
+            let corner = |i: usize| [(i + n - 1) % n, i, (i + 1) % n].map(|i| remaining[i]);
+            let is_ear = |i: usize| {
+                let [a, b, c] = corner(i);
+                orient2d(a, b, c) > 0.0
+                    && !remaining.iter().any(|&p| {
+                        p != a
+                            && p != b
+                            && p != c
+                            && orient2d(a, b, p) >= 0.0
+                            && orient2d(b, c, p) >= 0.0
+                            && orient2d(c, a, p) >= 0.0
+                    })
+            };
+            // without an ear the polygon isn't simple; clipping any corner at least terminates
+            let ear = (0..n).find(|&i| is_ear(i)).unwrap_or(0);
+            triangles.push(corner(ear));
+            remaining.remove(ear);
+        }
+        if let [a, b, c] = remaining[..] {
+            triangles.push([a, b, c]);
+        }
+        if flipped {
+            for t in &mut triangles {
+                t.reverse();
+            }
+        }
+        triangles
+    }
+
+    /// Returns the vertices with positive winding, starting from the lowest (then leftmost) vertex.
+    fn canonical(&self) -> Vec<Vec2> {
+        let mut vertices = self.vertices.clone();
+        if self.signed_area() < 0.0 {
+            vertices.reverse();
+        }
+        let start = (0..vertices.len())
+            .min_by(|&i, &j| {
+                let (a, b) = (vertices[i], vertices[j]);
+                a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x))
+            })
+            .unwrap_or(0);
+        vertices.rotate_left(start);
+        vertices
+    }
+}
+
+/// Returns the [Minkowski sum](https://en.wikipedia.org/wiki/Minkowski_addition) of two convex polygons:
+/// every point of `a` plus every point of `b`, with positive winding.
+///
+/// Inflating an obstacle by an agent's shape (mirrored through its origin) gives the positions where the agent would collide,
+/// so pathfinding can treat the agent as a point.
+/// ```
+/// # use vecto::{Vec2, shape::{Polygon, minkowski_sum}};
+/// let square = Polygon::new(vec![Vec2::ZERO, Vec2::new(2.0, 0.0), Vec2::splat(2.0), Vec2::new(0.0, 2.0)]);
+/// let agent = Polygon::new(vec![Vec2::splat(-1.0), Vec2::new(1.0, -1.0), Vec2::splat(1.0), Vec2::new(-1.0, 1.0)]);
+/// let inflated = minkowski_sum(&square, &agent);
+/// assert_eq!(inflated.vertices, [Vec2::splat(-1.0), Vec2::new(3.0, -1.0), Vec2::splat(3.0), Vec2::new(-1.0, 3.0)]);
+/// ```
+#[must_use]
+pub fn minkowski_sum(a: &Polygon, b: &Polygon) -> Polygon {
+    let (a, b) = (a.canonical(), b.canonical());
+    let (len_a, len_b) = (a.len(), b.len());
+    if len_a == 0 || len_b == 0 {
+        return Polygon::default();
+    }
+    let edge = |p: &[Vec2], i: usize| p[(i + 1) % p.len()] - p[i % p.len()];
+    let mut vertices = Vec::with_capacity(len_a + len_b);
+    let (mut i, mut j) = (0, 0);
+    // merge the edges of both, sorted by angle
+    while i < len_a || j < len_b {
+        vertices.push(a[i % len_a] + b[j % len_b]);
+        let turn = edge(&a, i).cross(&edge(&b, j));
+        if turn >= 0.0 && i < len_a {
+            i += 1;
+        }
+        if turn <= 0.0 && j < len_b {
+            j += 1;
+        }
+    }
+    Polygon::new(vertices)
+}
+
+/// Returns the Minkowski sum of a convex polygon and a circle, the polygon inflated by the radius with rounded corners
+/// (and moved by the circle's center).
+///
+/// The circle is approximated by a regular polygon with `segments` sides, circumscribing it so that the result
+/// always contains the exact sum.
+/// ```
+/// # use vecto::{Vec2, shape::{Circle, Polygon, minkowski_sum_circle}};
+/// let square = Polygon::new(vec![Vec2::ZERO, Vec2::new(2.0, 0.0), Vec2::splat(2.0), Vec2::new(0.0, 2.0)]);
+/// let padded = minkowski_sum_circle(&square, Circle::new(Vec2::ZERO, 0.5), 16);
+/// assert!(padded.contains_point(Vec2::new(2.45, 1.0)));
+/// assert!(!padded.contains_point(Vec2::new(2.55, 1.0)));
+/// assert!(padded.contains_point(Vec2::splat(2.0) + Vec2::splat(0.5f32.sqrt() * 0.5 - 0.01)));
+/// ```
+#[must_use]
+pub fn minkowski_sum_circle(polygon: &Polygon, circle: Circle, segments: usize) -> Polygon {
+    #[allow(clippy::cast_precision_loss)]
+    let radius = circle.radius / (std::f32::consts::PI / segments as f32).cos();
+    minkowski_sum(polygon, &Polygon::regular(circle.center, radius, segments))
+}
@@ -0,0 +1,33 @@
+use crate::Vec2;
+
+/// An open chain of line segments, through its vertices in order.
+/// ```
+/// # use vecto::{Vec2, shape::Polyline};
+/// let path = Polyline::new(vec![Vec2::ZERO, Vec2::new(3.0, 0.0), Vec2::new(3.0, 4.0)]);
+/// assert_eq!(path.length(), 7.0);
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Polyline {
+    /// The vertices, in order.
+    pub vertices: Vec<Vec2>,
+}
+
+impl Polyline {
+    /// Construct a new [`Polyline`].
+    #[must_use]
+    #[inline]
+    pub const fn new(vertices: Vec<Vec2>) -> Self {
+        Self { vertices }
+    }
+
+    /// Returns the segments, as pairs of consecutive vertices.
+    pub fn segments(&self) -> impl Iterator<Item = (Vec2, Vec2)> + Clone + '_ {
+        self.vertices.windows(2).map(|w| (w[0], w[1]))
+    }
+
+    /// Returns the total length of the segments.
+    #[must_use]
+    pub fn length(&self) -> f32 {
+        self.segments().map(|(a, b)| a.distance_to(&b)).sum()
+    }
+}
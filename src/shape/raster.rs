@@ -0,0 +1,73 @@
+use super::Polygon;
+use crate::{IVec2, Vec2};
+
+/// Which areas of a self-overlapping [`Polygon`] count as inside, for [`Polygon::rasterize`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum FillRule {
+    /// Inside where a ray out crosses an odd number of edges, so overlaps cancel out, like [`Polygon::contains_point`].
+    #[default]
+    EvenOdd,
+    /// Inside where the outline winds around a nonzero number of times, so overlaps stay filled.
+    NonZero,
+}
+
+impl Polygon {
+    /// Returns the cells of a grid with square cells `cell_size` wide that the polygon covers,
+    /// row by row from the top. Cell `(x, y)` spans `x * cell_size..(x + 1) * cell_size` horizontally (and likewise vertically),
+    /// and is covered if its center is inside the polygon.
+    /// ```
+    /// # use vecto::{IVec2, Vec2, shape::{FillRule, Polygon}};
+    /// let triangle = Polygon::new(vec![Vec2::ZERO, Vec2::new(4.0, 0.0), Vec2::new(0.0, 4.0)]);
+    /// let cells: Vec<_> = triangle.rasterize(1.0, FillRule::EvenOdd).collect();
+    /// assert_eq!(cells.len(), 6);
+    /// assert_eq!(cells[..4], [IVec2::new(0, 0), IVec2::new(1, 0), IVec2::new(2, 0), IVec2::new(0, 1)]);
+    /// // a star's middle overlaps itself
+    /// let star = Polygon::new([(5.0, 0.0), (8.0, 10.0), (0.0, 4.0), (10.0, 4.0), (2.0, 10.0)].map(Vec2::from).to_vec());
+    /// let center = IVec2::new(5, 5);
+    /// assert!(!star.rasterize(1.0, FillRule::EvenOdd).any(|c| c == center));
+    /// assert!(star.rasterize(1.0, FillRule::NonZero).any(|c| c == center));
+    /// ```
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn rasterize(&self, cell_size: f32, fill: FillRule) -> impl Iterator<Item = IVec2> + '_ {
+        let (top, bottom) = self
+            .vertices
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(top, bottom), p| {
+                (top.min(p.y), bottom.max(p.y))
+            });
+        let rows = if top <= bottom {
+            (top / cell_size).floor() as i32..(bottom / cell_size).ceil() as i32
+        } else {
+            0..0
+        };
+        rows.flat_map(move |y| {
+            let scan = (y as f32 + 0.5) * cell_size;
+            // where each edge crosses the scanline, and which way it winds
+            let mut crossings: Vec<(f32, i32)> = self
+                .edges()
+                .filter(|&(a, b)| (a.y <= scan) != (b.y <= scan))
+                .map(|(a, b): (Vec2, Vec2)| {
+                    let x = a.x + (scan - a.y) / (b.y - a.y) * (b.x - a.x);
+                    (x, if a.y < b.y { 1 } else { -1 })
+                })
+                .collect();
+            crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+            let mut spans = vec![];
+            let mut winding = 0;
+            for pair in crossings.windows(2) {
+                winding += pair[0].1;
+                let inside = match fill {
+                    FillRule::EvenOdd => winding % 2 != 0,
+                    FillRule::NonZero => winding != 0,
+                };
+                if inside {
+                    // the cells whose centers are in the span
+                    let first = (pair[0].0 / cell_size - 0.5).ceil() as i32;
+                    let end = (pair[1].0 / cell_size - 0.5).ceil() as i32;
+                    spans.push(first..end);
+                }
+            }
+            spans.into_iter().flatten().map(move |x| IVec2::new(x, y))
+        })
+    }
+}
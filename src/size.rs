@@ -0,0 +1,135 @@
+use crate::{Vec2, Vector2};
+
+/// How a scaled [`Size2`] is rounded to whole pixels.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum RoundingMode {
+    /// To the nearest pixel, halves away from zero.
+    #[default]
+    Nearest,
+    /// Down, never growing past the exact size.
+    Down,
+    /// Up, never shrinking below the exact size.
+    Up,
+}
+
+impl RoundingMode {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn round(self, value: f64) -> u32 {
+        // `as` saturates
+        let value = match self {
+            Self::Nearest => value.round(),
+            Self::Down => value.floor(),
+            Self::Up => value.ceil(),
+        };
+        value as u32
+    }
+}
+
+/// A size in whole pixels, such as an image or a window.
+/// ```
+/// # use vecto::{RoundingMode, Size2};
+/// let photo = Size2::new(4000, 3000);
+/// assert_eq!(photo.scaled_to_width(200, RoundingMode::Nearest), Size2::new(200, 150));
+/// assert_eq!(photo.constrain(Size2::new(256, 256), RoundingMode::Down), Size2::new(256, 192));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Size2 {
+    /// The width.
+    pub width: u32,
+    /// The height.
+    pub height: u32,
+}
+
+impl Size2 {
+    /// Construct a new [`Size2`].
+    #[must_use]
+    #[inline]
+    pub const fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+
+    /// Returns the aspect ratio, `width / height`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    pub fn aspect_ratio(&self) -> f32 {
+        (f64::from(self.width) / f64::from(self.height)) as f32
+    }
+
+    /// Returns the size scaled to `width`, keeping the aspect ratio. A size with no width keeps its height.
+    /// ```
+    /// # use vecto::{RoundingMode, Size2};
+    /// let s = Size2::new(3, 2);
+    /// assert_eq!(s.scaled_to_width(4, RoundingMode::Nearest), Size2::new(4, 3));
+    /// assert_eq!(s.scaled_to_width(4, RoundingMode::Down), Size2::new(4, 2));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn scaled_to_width(self, width: u32, rounding: RoundingMode) -> Self {
+        if self.width == 0 {
+            return Self::new(width, self.height);
+        }
+        let height = f64::from(self.height) * f64::from(width) / f64::from(self.width);
+        Self::new(width, rounding.round(height))
+    }
+
+    /// Returns the size scaled to `height`, keeping the aspect ratio. A size with no height keeps its width.
+    /// ```
+    /// # use vecto::{RoundingMode, Size2};
+    /// assert_eq!(Size2::new(1920, 1080).scaled_to_height(720, RoundingMode::Nearest), Size2::new(1280, 720));
+    /// assert_eq!(Size2::new(3, 2).scaled_to_height(3, RoundingMode::Up), Size2::new(5, 3));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn scaled_to_height(self, height: u32, rounding: RoundingMode) -> Self {
+        if self.height == 0 {
+            return Self::new(self.width, height);
+        }
+        let width = f64::from(self.width) * f64::from(height) / f64::from(self.height);
+        Self::new(rounding.round(width), height)
+    }
+
+    /// Returns the size shrunk to fit within `max`, keeping the aspect ratio. A size that already fits is unchanged.
+    ///
+    /// The result never exceeds `max`, even when rounding up.
+    /// ```
+    /// # use vecto::{RoundingMode, Size2};
+    /// let max = Size2::new(100, 100);
+    /// assert_eq!(Size2::new(300, 200).constrain(max, RoundingMode::Nearest), Size2::new(100, 67));
+    /// assert_eq!(Size2::new(50, 20).constrain(max, RoundingMode::Nearest), Size2::new(50, 20));
+    /// ```
+    #[must_use = "Does not modify in place."]
+    pub fn constrain(self, max: Self, rounding: RoundingMode) -> Self {
+        if self.width <= max.width && self.height <= max.height {
+            return self;
+        }
+        let scale = (f64::from(max.width) / f64::from(self.width))
+            .min(f64::from(max.height) / f64::from(self.height));
+        Self::new(
+            rounding.round(f64::from(self.width) * scale).min(max.width),
+            rounding
+                .round(f64::from(self.height) * scale)
+                .min(max.height),
+        )
+    }
+}
+
+impl From<Size2> for Vector2<u32> {
+    #[inline]
+    fn from(size: Size2) -> Self {
+        Self::new(size.width, size.height)
+    }
+}
+
+impl From<Vector2<u32>> for Size2 {
+    #[inline]
+    fn from(size: Vector2<u32>) -> Self {
+        Self::new(size.x, size.y)
+    }
+}
+
+impl From<Size2> for Vec2 {
+    #[allow(clippy::cast_precision_loss)]
+    #[inline]
+    fn from(size: Size2) -> Self {
+        Self::new(size.width as f32, size.height as f32)
+    }
+}
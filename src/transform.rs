@@ -0,0 +1,119 @@
+use crate::{Matrix2, Vec2};
+use core::ops::Mul;
+
+/// A 2D affine transform: a [`Matrix2`] basis, then a translation.
+/// ```
+/// # use vecto::{Transform2D, Vec2};
+/// let t = Transform2D::new(Vec2::new(2.0, 0.0), Vec2::new(0.0, 2.0), Vec2::new(10.0, 0.0));
+/// assert_eq!(t * Vec2::splat(1.0), Vec2::new(12.0, 2.0));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub struct Transform2D {
+    /// Where the X axis goes, the first column of the basis.
+    pub x: Vec2,
+    /// Where the Y axis goes, the second column of the basis.
+    pub y: Vec2,
+    /// The translation, applied after the basis.
+    pub origin: Vec2,
+}
+
+impl Default for Transform2D {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform2D {
+    /// The identity transform, which changes nothing.
+    pub const IDENTITY: Self = Self::new(Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0), Vec2::ZERO);
+
+    /// Construct a new [`Transform2D`] from its basis columns and origin.
+    #[must_use]
+    #[inline]
+    pub const fn new(x: Vec2, y: Vec2, origin: Vec2) -> Self {
+        Self { x, y, origin }
+    }
+
+    /// Construct a [`Transform2D`] that skews by `angle` radians, leaning the Y axis over while keeping it unit length,
+    /// like Godot's `Transform2D` skew.
+    /// ```
+    /// # use vecto::{Kinda, Transform2D, Vec2};
+    /// # use std::f32::consts::FRAC_PI_4;
+    /// let t = Transform2D::from_skew(FRAC_PI_4);
+    /// assert_eq!(t * Vec2::RIGHT, Vec2::RIGHT);
+    /// assert!((t * Vec2::DOWN).approx_eq(Vec2::new(-0.5f32.sqrt(), 0.5f32.sqrt())));
+    /// assert!(t.skew().kinda_eq(FRAC_PI_4, 1e-6));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_skew(angle: f32) -> Self {
+        Self::new(
+            Vec2::new(1.0, 0.0),
+            Vec2::new(-angle.sin(), angle.cos()),
+            Vec2::ZERO,
+        )
+    }
+
+    /// Returns the basis (the rotation, scale and skew, without the translation).
+    #[must_use]
+    #[inline]
+    pub fn basis(&self) -> Matrix2<f32> {
+        Matrix2::new(self.x, self.y)
+    }
+
+    /// Returns the skew angle in radians, how far the Y axis leans away from perpendicular to the X axis.
+    #[must_use]
+    #[inline]
+    pub fn skew(&self) -> f32 {
+        let sign = self.basis().determinant().signum();
+        (self.x.normalized().dot(&(self.y.normalized() * sign)))
+            .clamp(-1.0, 1.0)
+            .acos()
+            - std::f32::consts::FRAC_PI_2
+    }
+
+    /// Transforms every point in `points`, in place.
+    ///
+    /// Same as `*p = t * *p` for each point, but with the transform kept in registers so the loop vectorizes,
+    /// which is faster for whole meshes.
+    /// ```
+    /// # use vecto::{Transform2D, Vec2};
+    /// let t = Transform2D::new(Vec2::new(2.0, 0.0), Vec2::new(0.0, 2.0), Vec2::new(10.0, 0.0));
+    /// let mut points = [Vec2::ZERO, Vec2::splat(1.0)];
+    /// t.transform_slice(&mut points);
+    /// assert_eq!(points, [t * Vec2::ZERO, t * Vec2::splat(1.0)]);
+    /// ```
+    #[inline]
+    pub fn transform_slice(&self, points: &mut [Vec2]) {
+        let (xx, xy, yx, yy) = (self.x.x, self.x.y, self.y.x, self.y.y);
+        let (ox, oy) = (self.origin.x, self.origin.y);
+        for p in points {
+            let (x, y) = (p.x, p.y);
+            p.x = xx * x + yx * y + ox;
+            p.y = xy * x + yy * y + oy;
+        }
+    }
+}
+
+impl Mul<Vec2> for Transform2D {
+    type Output = Vec2;
+
+    /// Transforms a point.
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Vec2 {
+        self.basis() * rhs + self.origin
+    }
+}
+
+impl Mul for Transform2D {
+    type Output = Self;
+
+    /// Composes two transforms, so that `(a * b) * p == a * (b * p)`.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let basis = self.basis();
+        Self::new(basis * rhs.x, basis * rhs.y, self * rhs.origin)
+    }
+}
@@ -0,0 +1,176 @@
+//! Delaunay triangulation of point sets.
+//! ```
+//! # use vecto::{Vec2, triangulation::delaunay};
+//! let points = [Vec2::ZERO, Vec2::new(4.0, 0.0), Vec2::new(4.0, 3.0), Vec2::new(0.0, 3.0), Vec2::new(2.0, 1.0)];
+//! let mesh = delaunay(&points);
+//! assert_eq!(mesh.triangles().len(), 4);
+//! let t = mesh.locate(Vec2::new(2.0, 0.5)).unwrap();
+//! assert!(mesh.triangles()[t].contains(&4));
+//! assert_eq!(mesh.locate(Vec2::new(5.0, 1.0)), None);
+//! ```
+use crate::{
+    robust::{incircle, orient2d},
+    Vec2,
+};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// A triangulation of a set of points, covering their convex hull.
+///
+/// Triangles are stored as indices into the points, wound so that [`Vec2::cross`] of their edges is positive
+/// (clockwise on screen, as Y is down).
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Triangulation {
+    points: Vec<Vec2>,
+    triangles: Vec<[usize; 3]>,
+    neighbors: Vec<[Option<usize>; 3]>,
+}
+
+impl Triangulation {
+    /// Returns the points.
+    #[must_use]
+    pub fn points(&self) -> &[Vec2] {
+        &self.points
+    }
+
+    /// Returns the triangles, as indices into [`Triangulation::points`].
+    #[must_use]
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    /// Returns the corners of triangle `t`.
+    #[must_use]
+    pub fn triangle(&self, t: usize) -> [Vec2; 3] {
+        self.triangles[t].map(|i| self.points[i])
+    }
+
+    /// Returns the triangles sharing an edge with triangle `t`: element `i` is across the edge opposite corner `i`,
+    /// or [`None`] on the hull.
+    /// ```
+    /// # use vecto::{Vec2, triangulation::delaunay};
+    /// let mesh = delaunay(&[Vec2::ZERO, Vec2::new(1.0, 0.0), Vec2::new(1.0, 1.0), Vec2::new(0.0, 1.0)]);
+    /// let across: Vec<_> = mesh.neighbors(0).into_iter().flatten().collect();
+    /// assert_eq!(across, [1]);
+    /// ```
+    #[must_use]
+    pub fn neighbors(&self, t: usize) -> [Option<usize>; 3] {
+        self.neighbors[t]
+    }
+
+    /// Returns the triangle containing `p` (or one of the triangles sharing it, if it is on an edge),
+    /// or [`None`] if `p` is outside the hull.
+    ///
+    /// Walks across the mesh from the first triangle, so is fast for well shaped meshes.
+    #[must_use]
+    pub fn locate(&self, p: Vec2) -> Option<usize> {
+        let outside = |t: usize, i: usize| {
+            let corners = self.triangle(t);
+            let (a, b) = (corners[(i + 1) % 3], corners[(i + 2) % 3]);
+            orient2d(a, b, p) < 0.0
+        };
+        let mut t = 0;
+        // a walk can cycle on degenerate meshes; fall back to checking every triangle
+        for _ in 0..self.triangles.len() {
+            match (0..3).find(|&i| outside(t, i)) {
+                None => return Some(t),
+                Some(i) => t = self.neighbors[t][i]?,
+            }
+        }
+        (0..self.triangles.len()).find(|&t| (0..3).all(|i| !outside(t, i)))
+    }
+}
+
+/// Returns the [Delaunay triangulation](https://en.wikipedia.org/wiki/Delaunay_triangulation) of the points:
+/// the triangulation whose triangles' circumcircles contain no other points, which avoids slivers.
+///
+/// The triangles cover the convex hull, even where it is nearly flat.
+/// Duplicate points are left out of every triangle. If the points are all collinear, there are no triangles.
+///
+/// Uses the Bowyer–Watson algorithm, checking every triangle on each insertion, so it always takes `Θ(n²)` time.
+/// ```
+/// # use vecto::{Vec2, triangulation::delaunay};
+/// let points = [(0.0, 0.0), (1.0, 0.0), (2.0, 1e-4), (3.0, 0.0), (4.0, 0.0), (2.0, -1.0)].map(Vec2::from);
+/// let mesh = delaunay(&points);
+/// assert_eq!(mesh.triangles().len(), 6);
+/// assert!(mesh.triangles().contains(&[0, 1, 2]) && mesh.triangles().contains(&[2, 3, 4]));
+/// assert!(mesh.locate(Vec2::new(1.0, 2e-5)).is_some());
+/// ```
+#[must_use]
+pub fn delaunay(points: &[Vec2]) -> Triangulation {
+    // stands in for a point at infinity: the triangle [a, b, GHOST] lies outside the hull edge from `b` to `a`
+    const GHOST: usize = usize::MAX;
+    let n = points.len();
+    // the first triangle, from the first three points not all on a line
+    let Some(first) = (1..n).find(|&i| points[i] != points[0]).and_then(|j| {
+        (j + 1..n)
+            .find(|&k| orient2d(points[0], points[j], points[k]) != 0.0)
+            .map(|k| [0, j, k])
+    }) else {
+        return Triangulation {
+            points: points.to_vec(),
+            ..Triangulation::default()
+        };
+    };
+    let [a, mut b, mut c] = first;
+    if orient2d(points[a], points[b], points[c]) < 0.0 {
+        (b, c) = (c, b);
+    }
+    let mut triangles = vec![[a, b, c], [b, a, GHOST], [c, b, GHOST], [a, c, GHOST]];
+    let circumcircle_contains = |[a, b, c]: [usize; 3], p: Vec2| {
+        if c != GHOST {
+            return incircle(points[a], points[b], points[c], p) > 0.0;
+        }
+        // the circumcircle of a ghost triangle is the half plane beyond its edge, and the open edge itself
+        let (a, b) = (points[a], points[b]);
+        let side = orient2d(a, b, p);
+        side > 0.0 || (side == 0.0 && (p - a).dot(&(b - a)) > 0.0 && (p - b).dot(&(a - b)) > 0.0)
+    };
+    let mut seen: HashSet<_> = first
+        .iter()
+        .map(|&i| (points[i].x.to_bits(), points[i].y.to_bits()))
+        .collect();
+    // ordered, so the triangulation is deterministic
+    let mut edges = BTreeSet::new();
+    for (i, &p) in points.iter().enumerate() {
+        if !seen.insert((p.x.to_bits(), p.y.to_bits())) {
+            continue;
+        }
+        // carve out the triangles whose circumcircles contain p, and fan the hole from p
+        edges.clear();
+        triangles.retain(|&[a, b, c]| {
+            if !circumcircle_contains([a, b, c], p) {
+                return true;
+            }
+            for (from, to) in [(a, b), (b, c), (c, a)] {
+                // an edge shared by two removed triangles is inside the hole
+                if !edges.remove(&(to, from)) {
+                    edges.insert((from, to));
+                }
+            }
+            false
+        });
+        triangles.extend(edges.iter().map(|&(from, to)| match (from, to) {
+            // keep the ghost last
+            (GHOST, to) => [to, i, GHOST],
+            (from, GHOST) => [i, from, GHOST],
+            (from, to) => [from, to, i],
+        }));
+    }
+    triangles.retain(|&[.., c]| c != GHOST);
+
+    let mut edges = HashMap::new();
+    for (t, &[a, b, c]) in triangles.iter().enumerate() {
+        for (i, edge) in [(b, c), (c, a), (a, b)].into_iter().enumerate() {
+            edges.insert(edge, (t, i));
+        }
+    }
+    let neighbors = triangles
+        .iter()
+        .map(|&[a, b, c]| [(c, b), (a, c), (b, a)].map(|edge| edges.get(&edge).map(|&(t, _)| t)))
+        .collect();
+    Triangulation {
+        points: points.to_vec(),
+        triangles,
+        neighbors,
+    }
+}
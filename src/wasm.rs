@@ -0,0 +1,115 @@
+//! A [`wasm_bindgen`] wrapper around [`Vec2`], for passing vectors to and from JavaScript.
+use crate::Vec2;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// A [`Vec2`] exported to JavaScript as `Vec2`.
+/// ```
+/// # use vecto::{Vec2, wasm::JsVec2};
+/// let v = JsVec2::new(3.0, 4.0);
+/// assert_eq!(v.length(), 5.0);
+/// assert_eq!(Vec2::from(v.add(&JsVec2::new(1.0, 1.0))), Vec2::new(4.0, 5.0));
+/// ```
+#[wasm_bindgen(js_name = Vec2)]
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct JsVec2 {
+    /// The vector's X component.
+    pub x: f32,
+    /// The vector's Y component.
+    pub y: f32,
+}
+
+#[wasm_bindgen(js_class = Vec2)]
+impl JsVec2 {
+    /// Construct a new [`JsVec2`].
+    #[must_use]
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// See [`Vec2::length`].
+    #[must_use]
+    pub fn length(&self) -> f32 {
+        Vec2::from(*self).length()
+    }
+
+    /// See [`Vec2::length_squared`].
+    #[must_use]
+    #[wasm_bindgen(js_name = lengthSquared)]
+    pub fn length_squared(&self) -> f32 {
+        Vec2::from(*self).length_squared()
+    }
+
+    /// See [`Vec2::angle`].
+    #[must_use]
+    pub fn angle(&self) -> f32 {
+        Vec2::from(*self).angle()
+    }
+
+    /// See [`Vec2::normalized`].
+    #[must_use = "Does not modify in place."]
+    pub fn normalized(&self) -> Self {
+        Vec2::from(*self).normalized().into()
+    }
+
+    /// See [`Vec2::rotated`].
+    #[must_use = "Does not modify in place."]
+    pub fn rotated(&self, angle: f32) -> Self {
+        Vec2::from(*self).rotated(angle).into()
+    }
+
+    /// See [`Vec2::lerp`].
+    #[must_use = "Does not modify in place."]
+    pub fn lerp(&self, to: &Self, t: f32) -> Self {
+        Vec2::from(*self).lerp((*to).into(), t).into()
+    }
+
+    /// See [`Vec2::dot`].
+    #[must_use]
+    pub fn dot(&self, with: &Self) -> f32 {
+        Vec2::from(*self).dot(&(*with).into())
+    }
+
+    /// See [`Vec2::cross`].
+    #[must_use]
+    pub fn cross(&self, with: &Self) -> f32 {
+        Vec2::from(*self).cross(&(*with).into())
+    }
+
+    /// See [`Vec2::distance_to`].
+    #[must_use]
+    #[wasm_bindgen(js_name = distanceTo)]
+    pub fn distance_to(&self, to: &Self) -> f32 {
+        Vec2::from(*self).distance_to(&(*to).into())
+    }
+
+    /// Returns `self + rhs`.
+    #[must_use = "Does not modify in place."]
+    pub fn add(&self, rhs: &Self) -> Self {
+        (Vec2::from(*self) + Vec2::from(*rhs)).into()
+    }
+
+    /// Returns `self - rhs`.
+    #[must_use = "Does not modify in place."]
+    pub fn sub(&self, rhs: &Self) -> Self {
+        (Vec2::from(*self) - Vec2::from(*rhs)).into()
+    }
+
+    /// Returns `self * scale`.
+    #[must_use = "Does not modify in place."]
+    pub fn scale(&self, scale: f32) -> Self {
+        (Vec2::from(*self) * scale).into()
+    }
+}
+
+impl From<Vec2> for JsVec2 {
+    fn from(Vec2 { x, y }: Vec2) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<JsVec2> for Vec2 {
+    fn from(JsVec2 { x, y }: JsVec2) -> Self {
+        Self::new(x, y)
+    }
+}